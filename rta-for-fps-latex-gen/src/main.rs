@@ -1,25 +1,14 @@
-use rta_for_fps_latex_lib::{CurveWindows, TotalDemandCurve};
-use rta_for_fps_lib::curve::{AggregateExt, Curve};
+use rta_for_fps_latex_lib::{CurveWindows, SvgCurve, TotalDemandCurve};
 use rta_for_fps_lib::iterators::curve::CurveSplitIterator;
-use rta_for_fps_lib::iterators::{CurveIterator, ReclassifyIterator};
-use rta_for_fps_lib::server::{
-    ActualServerExecution, AggregatedServerDemand, ConstrainedServerDemand, Server, ServerKind,
-    UnconstrainedServerExecution,
-};
-use rta_for_fps_lib::system::System;
-use rta_for_fps_lib::task::curve_types::TaskDemand;
-use rta_for_fps_lib::task::Task;
-use rta_for_fps_lib::time::TimeUnit;
-use rta_for_fps_lib::window::Window;
+use rta_for_fps_lib::iterators::ReclassifyIterator;
+use rta_for_fps_lib::prelude::*;
 
 fn main() -> std::io::Result<()> {
     let t_1 = Task::new(1, 5, 0);
     let t_2 = Task::new(2, 8, 0);
 
-    let condition = |window: &Window<_>| window.end <= TimeUnit::from(50);
-
-    let t1_curve = t_1.into_iter().take_while(condition);
-    let t2_curve = t_2.into_iter().take_while(condition);
+    let t1_curve = t_1.bounded_demand_iter(TimeUnit::from(50));
+    let t2_curve = t_2.bounded_demand_iter(TimeUnit::from(50));
 
     let t1 = t1_curve.clone().collect_curve();
     let t2 = t2_curve.clone().collect_curve();
@@ -105,12 +94,17 @@ fn main() -> std::io::Result<()> {
         CurveSplitIterator::new(system.original_actual_execution_curve_iter(1), limit)
             .take_while(|window| window.end <= limit);
 
+    let actual_execution: Curve<ActualServerExecution> =
+        unsafe { Curve::from_windows_unchecked(actual_execution.collect()) };
+
     std::fs::write(
         "latex/data/actual_execution.tex",
-        CurveWindows::new(unsafe {
-            Curve::<ActualServerExecution>::from_windows_unchecked(actual_execution.collect())
-        })
-        .to_string(),
+        CurveWindows::new(actual_execution.clone()).to_string(),
+    )?;
+
+    std::fs::write(
+        "latex/data/actual_execution.svg",
+        SvgCurve::new(actual_execution, 240, 10, 10).to_string(),
     )?;
 
     Ok(())
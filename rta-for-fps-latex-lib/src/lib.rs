@@ -1,32 +1,22 @@
 use std::fmt::{Display, Formatter};
 
+use rta_for_fps_lib::system::{OwnedSystem, System};
+use rta_for_fps_lib::time::TimeUnit;
 use rta_for_fps_lib::window::WindowEnd;
 use rta_for_fps_lib::{
     curve::curve_types::CurveType, curve::Curve, window::Demand, window::Window,
 };
 
 pub struct TotalDemandCurve {
-    steps: Vec<Window<Demand>>,
+    points: Vec<(TimeUnit, TimeUnit)>,
 }
 
 impl Display for TotalDemandCurve {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "x,y")?;
-        let mut summed_demand = 0;
 
-        for window in &self.steps {
-            let window_start = window.start.as_unit();
-            writeln!(f, "{x},{y}", x = window_start, y = summed_demand)?;
-
-            match window.length() {
-                WindowEnd::Finite(length) => {
-                    let length = length.as_unit();
-                    let window_end = window_start + length;
-                    summed_demand += length;
-                    writeln!(f, "{x},{y}", x = window_end, y = summed_demand)?;
-                }
-                WindowEnd::Infinite => {}
-            }
+        for (x, y) in &self.points {
+            writeln!(f, "{x},{y}", x = x.as_unit(), y = y.as_unit())?;
         }
         Ok(())
     }
@@ -35,7 +25,7 @@ impl Display for TotalDemandCurve {
 impl TotalDemandCurve {
     pub fn new<C: CurveType<WindowKind = Demand>>(curve: Curve<C>) -> Self {
         TotalDemandCurve {
-            steps: curve.into_windows(),
+            points: curve.to_step_points(),
         }
     }
 }
@@ -47,9 +37,9 @@ pub struct CurveWindows<W> {
 impl<W> Display for CurveWindows<W> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         for window in self.windows.iter() {
-            let length = match window.length() {
-                WindowEnd::Finite(length) => length,
-                WindowEnd::Infinite => continue,
+            let length = match window.length_finite() {
+                Some(length) => length,
+                None => continue,
             };
             writeln!(
                 f,
@@ -69,3 +59,137 @@ impl<W> CurveWindows<W> {
         }
     }
 }
+
+pub struct SvgCurve<W> {
+    windows: Vec<Window<W>>,
+    width: usize,
+    height: usize,
+    time_scale: usize,
+}
+
+impl<W> Display for SvgCurve<W> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">",
+            width = self.width,
+            height = self.height
+        )?;
+        writeln!(
+            f,
+            "<defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"6\" markerHeight=\"6\" orient=\"auto\"><path d=\"M 0 0 L 10 5 L 0 10 z\"/></marker></defs>"
+        )?;
+
+        for window in self.windows.iter() {
+            let x = window.start.as_unit() * self.time_scale;
+            match window.length() {
+                WindowEnd::Finite(length) => {
+                    writeln!(
+                        f,
+                        "<rect x=\"{x}\" y=\"0\" width=\"{width}\" height=\"{height}\"/>",
+                        x = x,
+                        width = length.as_unit() * self.time_scale,
+                        height = self.height
+                    )?;
+                }
+                WindowEnd::Infinite => {
+                    writeln!(
+                        f,
+                        "<rect x=\"{x}\" y=\"0\" width=\"{width}\" height=\"{height}\"/>",
+                        x = x,
+                        width = self.width.saturating_sub(x),
+                        height = self.height
+                    )?;
+                    writeln!(
+                        f,
+                        "<line x1=\"{x}\" y1=\"{y}\" x2=\"{end}\" y2=\"{y}\" stroke=\"black\" marker-end=\"url(#arrow)\"/>",
+                        x = x.min(self.width),
+                        y = self.height / 2,
+                        end = self.width
+                    )?;
+                }
+            }
+        }
+
+        writeln!(f, "</svg>")
+    }
+}
+
+impl<W> SvgCurve<W> {
+    pub fn new<C: CurveType<WindowKind = W>>(
+        curve: Curve<C>,
+        width: usize,
+        height: usize,
+        time_scale: usize,
+    ) -> Self {
+        SvgCurve {
+            windows: curve.into_windows(),
+            width,
+            height,
+            time_scale,
+        }
+    }
+}
+
+/// Graphviz DOT rendering of a System,
+/// with one cluster per Server containing its Tasks
+/// and dashed edges linking the Servers from higher to lower priority
+pub struct SystemDot {
+    system: OwnedSystem,
+}
+
+impl Display for SystemDot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "digraph system {{")?;
+
+        for (server_index, server) in self.system.as_owned_servers().iter().enumerate() {
+            let properties = server.properties;
+
+            writeln!(f, "    subgraph cluster_s{} {{", server_index)?;
+            writeln!(
+                f,
+                "        s{index} [shape=box, label=\"S{index}\\ncapacity {capacity}, interval {interval}\\n{kind:?}\"];",
+                index = server_index,
+                capacity = properties.capacity.as_unit(),
+                interval = properties.interval.as_unit(),
+                kind = properties.server_type
+            )?;
+
+            for (task_index, task) in server.tasks.iter().enumerate() {
+                writeln!(
+                    f,
+                    "        s{server}_t{task} [label=\"T{server},{task}\\ndemand {demand}, interval {interval}, offset {offset}\"];",
+                    server = server_index,
+                    task = task_index,
+                    demand = task.demand.as_unit(),
+                    interval = task.interval.as_unit(),
+                    offset = task.offset.as_unit()
+                )?;
+                writeln!(f, "        s{0} -> s{0}_t{1};", server_index, task_index)?;
+            }
+
+            writeln!(f, "    }}")?;
+        }
+
+        // servers in priority order, from higher to lower priority
+        for server_index in 1..self.system.as_owned_servers().len() {
+            writeln!(
+                f,
+                "    s{} -> s{} [style=dashed, label=\"priority\"];",
+                server_index - 1,
+                server_index
+            )?;
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+impl SystemDot {
+    /// Snapshot `system` for rendering, the DOT output is produced by `Display`
+    pub fn new(system: &System<'_>) -> Self {
+        SystemDot {
+            system: OwnedSystem::from(system),
+        }
+    }
+}
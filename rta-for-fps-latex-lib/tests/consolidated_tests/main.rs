@@ -1,9 +1,13 @@
-use rta_for_fps_latex_lib::TotalDemandCurve;
-use rta_for_fps_lib::curve::AggregateExt;
+use rta_for_fps_latex_lib::{SvgCurve, SystemDot, TotalDemandCurve};
+use rta_for_fps_lib::curve::curve_types::UnspecifiedCurve;
+use rta_for_fps_lib::curve::{AggregateExt, Curve};
 use rta_for_fps_lib::iterators::{CurveIterator, ReclassifyIterator};
+use rta_for_fps_lib::server::{Server, ServerKind};
+use rta_for_fps_lib::system::System;
 use rta_for_fps_lib::task::curve_types::TaskDemand;
 use rta_for_fps_lib::task::Task;
 use rta_for_fps_lib::time::TimeUnit;
+use rta_for_fps_lib::window::{Demand, Window, WindowEnd};
 
 #[test]
 fn figure_4_t1() {
@@ -117,3 +121,68 @@ fn figure_4_aggregate() {
     "
     )
 }
+
+#[test]
+fn svg_curve() {
+    let curve: Curve<UnspecifiedCurve<Demand>> = unsafe {
+        Curve::from_windows_unchecked(vec![Window::new(0, 2), Window::new(5, WindowEnd::Infinite)])
+    };
+
+    let svg = SvgCurve::new(curve, 100, 10, 10).to_string();
+
+    assert_eq!(
+        svg,
+        "\
+    <svg xmlns=\"http://www.w3.org/2000/svg\" width=\"100\" height=\"10\" viewBox=\"0 0 100 10\">\n\
+    <defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"6\" markerHeight=\"6\" orient=\"auto\"><path d=\"M 0 0 L 10 5 L 0 10 z\"/></marker></defs>\n\
+    <rect x=\"0\" y=\"0\" width=\"20\" height=\"10\"/>\n\
+    <rect x=\"50\" y=\"0\" width=\"50\" height=\"10\"/>\n\
+    <line x1=\"50\" y1=\"5\" x2=\"100\" y2=\"5\" stroke=\"black\" marker-end=\"url(#arrow)\"/>\n\
+    </svg>\n\
+    "
+    )
+}
+
+#[test]
+fn system_dot() {
+    let tasks_s1 = &[Task::new(1, 4, 0)];
+    let tasks_s2 = &[Task::new(1, 5, 0), Task::new(2, 8, 3)];
+
+    let servers = &[
+        Server::new(
+            tasks_s1,
+            TimeUnit::from(3),
+            TimeUnit::from(10),
+            ServerKind::Deferrable,
+        ),
+        Server::new(
+            tasks_s2,
+            TimeUnit::from(2),
+            TimeUnit::from(4),
+            ServerKind::Periodic,
+        ),
+    ];
+
+    let system = System::new(servers);
+
+    assert_eq!(
+        SystemDot::new(&system).to_string(),
+        "\
+digraph system {
+    subgraph cluster_s0 {
+        s0 [shape=box, label=\"S0\\ncapacity 3, interval 10\\nDeferrable\"];
+        s0_t0 [label=\"T0,0\\ndemand 1, interval 4, offset 0\"];
+        s0 -> s0_t0;
+    }
+    subgraph cluster_s1 {
+        s1 [shape=box, label=\"S1\\ncapacity 2, interval 4\\nPeriodic\"];
+        s1_t0 [label=\"T1,0\\ndemand 1, interval 5, offset 0\"];
+        s1 -> s1_t0;
+        s1_t1 [label=\"T1,1\\ndemand 2, interval 8, offset 3\"];
+        s1 -> s1_t1;
+    }
+    s0 -> s1 [style=dashed, label=\"priority\"];
+}
+"
+    );
+}
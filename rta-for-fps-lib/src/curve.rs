@@ -2,12 +2,14 @@
 //!
 //! and all associated functions
 
+use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt::Debug;
 
-use curve_types::CurveType;
+use curve_types::{CurveType, UnspecifiedCurve};
 
-use crate::iterators::curve::{CurveDeltaIterator, Delta};
+use crate::iterators::curve::{CurveDeltaIterator, CurveSplitIterator, Delta};
 use crate::iterators::CurveIterator;
 use crate::server::{ServerKind, ServerProperties};
 use crate::time::{TimeUnit, UnitNumber};
@@ -32,6 +34,36 @@ impl<C: CurveType> PartialEq for Curve<C> {
     }
 }
 
+impl<C: CurveType> PartialOrd for Curve<C>
+where
+    Self: Eq,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares the windows lexicographically by their `(start, end)`
+///
+/// The order is structural, not semantic,
+/// two curves providing the same capacity with differently split windows compare unequal
+impl<C: CurveType> Ord for Curve<C>
+where
+    Self: Eq,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.windows
+            .iter()
+            .map(|window| (window.start, window.end))
+            .cmp(
+                other
+                    .windows
+                    .iter()
+                    .map(|window| (window.start, window.end)),
+            )
+    }
+}
+
 impl<C: CurveType> Clone for Curve<C> {
     fn clone(&self) -> Self {
         Curve {
@@ -40,6 +72,15 @@ impl<C: CurveType> Clone for Curve<C> {
     }
 }
 
+/// Extend the Curve by pushing every window, see [`Curve::push`]
+impl<C: CurveType> Extend<Window<C::WindowKind>> for Curve<C> {
+    fn extend<I: IntoIterator<Item = Window<C::WindowKind>>>(&mut self, iter: I) {
+        for window in iter {
+            self.push(window);
+        }
+    }
+}
+
 impl<T: CurveType> Curve<T> {
     /// Create a new Curve from the provided window
     ///
@@ -70,11 +111,55 @@ impl<T: CurveType> Curve<T> {
         self.windows
     }
 
+    /// Returns a mutable reference to the contained windows
+    /// for editing the Curve in place
+    ///
+    /// # Safety
+    /// Windows need to remain non-overlapping, non-empty and
+    /// ordered based on start, to fulfill invariants of curve
+    pub const unsafe fn as_mut_windows(&mut self) -> &mut Vec<Window<T::WindowKind>> {
+        &mut self.windows
+    }
+
+    /// Insert `window` into the Curve at its sorted position,
+    /// joining it with the windows it overlaps or is adjacent to
+    ///
+    /// Unlike [`Curve::from_windows_unchecked`] this upholds the Curve invariants for any window,
+    /// an empty window is ignored.
+    ///
+    /// Each push costs O(n) for a Curve of n windows,
+    /// prefer [`Curve::from_windows_unchecked`] for constructing a Curve from windows known to be in order
+    pub fn push(&mut self, window: Window<T::WindowKind>) {
+        if window.is_empty() {
+            return;
+        }
+
+        // the first window not ending before the new window starts
+        let first = self
+            .windows
+            .partition_point(|other| other.end < window.start);
+
+        // the windows starting before the end of the new window, or at its end, are joined
+        let joined = self.windows[first..]
+            .iter()
+            .take_while(|other| window.end >= other.start)
+            .count();
+
+        let window = self
+            .windows
+            .drain(first..first + joined)
+            .fold(window, |window, other| {
+                Window::new(window.start.min(other.start), window.end.max(other.end))
+            });
+
+        self.windows.insert(first, window);
+    }
+
     /// Create a new empty Curve
     #[must_use]
-    pub fn empty() -> Self {
+    pub const fn empty() -> Self {
         Self {
-            windows: alloc::vec![],
+            windows: Vec::new(),
         }
     }
 
@@ -83,26 +168,204 @@ impl<T: CurveType> Curve<T> {
     /// by the list of windows.
     ///
     /// # Safety
-    /// Windows need to be non-overlapping and
+    /// Windows need to be non-overlapping, non-empty and
     /// ordered based on start, to fulfill invariants of curve
+    ///
+    /// Violating the non-empty invariant is caught by a debug assertion
     #[must_use]
     pub unsafe fn from_windows_unchecked(windows: Vec<Window<T::WindowKind>>) -> Self {
+        debug_assert!(
+            windows.iter().all(|window| !window.is_empty()),
+            "Curves must not contain empty windows, got: {:?}",
+            windows
+        );
+
         Self { windows }
     }
 
     /// Return the Curves Capacity as defined by Definition 3. in the paper
+    ///
+    /// The Capacity is [`WindowEnd::Infinite`] when the Curve contains an infinite window,
+    /// as is the case e.g. for unconstrained execution curves
     #[must_use]
     pub fn capacity(&self) -> WindowEnd {
         self.windows.iter().map(Window::length).sum()
     }
 
+    /// Calculate the points of the step function of the Curves cumulative length,
+    /// e.g. for plotting the total demand over time
+    ///
+    /// Every window contributes a point at its start with the length of all previous windows
+    /// and a point at its end including its own length,
+    /// the end point of an infinite window is omitted
+    #[must_use]
+    pub fn to_step_points(&self) -> Vec<(TimeUnit, TimeUnit)> {
+        let mut sum = TimeUnit::ZERO;
+        let mut points = Vec::with_capacity(2 * self.windows.len());
+
+        for window in &self.windows {
+            points.push((window.start, sum));
+
+            if let Some(length) = window.length_finite() {
+                sum += length;
+                points.push((window.start + length, sum));
+            }
+        }
+
+        points
+    }
+
+    /// Return the length of the longest window of the Curve
+    ///
+    /// [`WindowEnd::Infinite`] when the Curve contains an infinite window
+    /// and zero for an empty Curve
+    #[must_use]
+    pub fn max_window_length(&self) -> WindowEnd {
+        self.windows
+            .iter()
+            .map(Window::length)
+            .fold(WindowEnd::Finite(TimeUnit::ZERO), WindowEnd::max)
+    }
+
+    /// Return the length of the shortest gap between two consecutive windows of the Curve
+    ///
+    /// `None` when the Curve has less than two windows and therefore no gaps
+    #[must_use]
+    pub fn min_gap(&self) -> Option<TimeUnit> {
+        // only the last window can be infinite, so every window followed by another is finite
+        self.windows
+            .windows(2)
+            .filter_map(|pair| pair[0].end.as_finite().map(|end| pair[1].start - end))
+            .min()
+    }
+
     /// Return true if the Capacity of the Curve is 0
+    ///
+    /// As a Curve only contains non-empty windows this is the case exactly when it contains no windows
     #[must_use]
     pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    /// Calculate for each time in `times` the combined length of the Curve before that time
+    ///
+    /// Windows ending before a time contribute their full length,
+    /// a window containing the time contributes the part before the time
+    ///
+    /// `times` need to be sorted ascending as the windows are only walked once,
+    /// this is checked by a debug assertion
+    #[must_use]
+    pub fn cumulative_at(&self, times: &[TimeUnit]) -> Vec<TimeUnit> {
+        debug_assert!(
+            times.windows(2).all(|pair| pair[0] <= pair[1]),
+            "Times need to be sorted, got: {:?}",
+            times
+        );
+
+        let mut windows = self.windows.iter().peekable();
+        // the combined length of the windows ending before the current time
+        let mut completed = TimeUnit::ZERO;
+
+        times
+            .iter()
+            .map(|&time| {
+                while let Some(window) = windows.peek() {
+                    match window.end {
+                        WindowEnd::Finite(end) if end <= time => {
+                            completed += end - window.start;
+                            windows.next();
+                        }
+                        _ => break,
+                    }
+                }
+
+                let partial = windows
+                    .peek()
+                    .filter(|window| window.start < time)
+                    .map_or(TimeUnit::ZERO, |window| time - window.start);
+
+                completed + partial
+            })
+            .collect()
+    }
+
+    /// Get the parts of the windows of the Curve within `[start, end)`
+    ///
+    /// Windows crossing `start` or `end` are clipped to the range,
+    /// the windows in range are found using binary search
+    #[must_use]
+    pub fn windows_in_range(&self, start: TimeUnit, end: TimeUnit) -> Vec<Window<T::WindowKind>> {
+        if end <= start {
+            return Vec::new();
+        }
+
+        let first = self.windows.partition_point(|window| window.end <= start);
+        let last = self.windows.partition_point(|window| window.start < end);
+
         self.windows
+            .get(first..last)
+            .unwrap_or_default()
             .iter()
-            .map(Window::length)
-            .all(|c| c == TimeUnit::ZERO)
+            .map(|window| {
+                let (_, tail) = window.split_at(start);
+                let (head, _) = tail.split_at(end);
+                head
+            })
+            .collect()
+    }
+
+    /// Split the Curve into groups of `interval` length as defined in Definition 8. of the paper,
+    /// windows straddling a group boundary are split into one part for each group
+    ///
+    /// Returns the Curves of the non-empty groups indexed by their budget group,
+    /// the eager counterpart to [`CurveSplitIterator`]
+    ///
+    /// An infinite last window is split at most once, with the infinite part forming the last group
+    ///
+    /// # Panics
+    /// When the interval is zero
+    #[must_use]
+    pub fn split(&self, interval: TimeUnit) -> BTreeMap<UnitNumber, Self> {
+        let mut groups: BTreeMap<UnitNumber, Vec<_>> = BTreeMap::new();
+
+        for window in CurveSplitIterator::new(self.iter_windows(), interval) {
+            groups
+                .entry(window.budget_group(interval))
+                .or_default()
+                .push(window);
+        }
+
+        groups
+            .into_iter()
+            .map(|(group, windows)| {
+                // Safety:
+                // the windows of a group are a split part of this Curve
+                // and windows are only split at group boundaries
+                let curve = unsafe { Curve::from_windows_unchecked(windows) };
+                (group, curve)
+            })
+            .collect()
+    }
+
+    /// Calculate the capacity of each group of `interval` length as defined in Definition 8. of the paper,
+    /// the combined length of the windows within the group
+    ///
+    /// Windows straddling a group boundary contribute to each group their part within it,
+    /// only non-empty groups are included, see [`Curve::split`]
+    ///
+    /// # Panics
+    /// When the interval is zero or the Curve ends with an infinite window
+    #[must_use]
+    pub fn capacity_per_interval(&self, interval: TimeUnit) -> BTreeMap<UnitNumber, TimeUnit> {
+        let mut capacities = BTreeMap::new();
+
+        for window in CurveSplitIterator::new(self.iter_windows(), interval) {
+            *capacities
+                .entry(window.budget_group(interval))
+                .or_insert(TimeUnit::ZERO) += window.length_or_panic();
+        }
+
+        capacities
     }
 
     /// Change the `CurveType` of the Curve,
@@ -114,6 +377,131 @@ impl<T: CurveType> Curve<T> {
         }
     }
 
+    /// Stretch the Curve by `factor`, scaling every window with [`Window::scale`]
+    ///
+    /// Unlike shifting this also changes the spacing of the windows,
+    /// as a uniform positive scaling it preserves their order and keeps them non-overlapping,
+    /// so the Curve invariants are preserved
+    ///
+    /// # Panics
+    /// When `factor` is 0
+    #[must_use]
+    pub fn stretch(self, factor: UnitNumber) -> Self {
+        assert!(factor > 0, "Can't stretch a Curve by 0!");
+
+        let windows = self
+            .windows
+            .iter()
+            .map(|window| window.scale(factor))
+            .collect();
+
+        Curve { windows }
+    }
+
+    /// Scale the time of the Curve by `factor`,
+    /// named consistently with [`Task::scale_time`](crate::task::Task::scale_time),
+    /// see [`Curve::stretch`]
+    ///
+    /// # Panics
+    /// When `factor` is 0
+    #[must_use]
+    pub fn scale_time(self, factor: UnitNumber) -> Self {
+        self.stretch(factor)
+    }
+
+    /// Merge adjacent windows of the Curve
+    /// i.e. windows where the end of the previous window is the start of the next window
+    ///
+    /// The eager counterpart to [`CurveIterator::normalize`]
+    #[must_use]
+    pub fn merge_adjacent(self) -> Self {
+        let mut windows: Vec<Window<T::WindowKind>> = Vec::with_capacity(self.windows.len());
+
+        for window in self.windows {
+            match windows.last_mut() {
+                Some(last) if last.end == window.start => last.end = window.end,
+                _ => windows.push(window),
+            }
+        }
+
+        Curve { windows }
+    }
+
+    /// Calculate the temporal union of two Curves
+    ///
+    /// The resulting Curve covers all points in time covered by either Curve,
+    /// overlapping and adjacent windows are joined.
+    ///
+    /// Unlike aggregation the length of overlapping windows is not summed
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut left = self.windows.iter().peekable();
+        let mut right = other.windows.iter().peekable();
+
+        let mut windows: Vec<Window<T::WindowKind>> =
+            Vec::with_capacity(self.windows.len() + other.windows.len());
+
+        loop {
+            let next = match (left.peek(), right.peek()) {
+                (None, None) => break,
+                (Some(_), None) => left.next(),
+                (None, Some(_)) => right.next(),
+                (Some(l), Some(r)) => {
+                    if l.start <= r.start {
+                        left.next()
+                    } else {
+                        right.next()
+                    }
+                }
+            };
+
+            if let Some(window) = next {
+                match windows.last_mut() {
+                    Some(last) if window.start <= last.end => {
+                        last.end = WindowEnd::max(last.end, window.end);
+                    }
+                    _ => windows.push(window.clone()),
+                }
+            }
+        }
+
+        Curve { windows }
+    }
+
+    /// Calculate the complement of the Curve within `[0, limit]`
+    ///
+    /// The finite counterpart to [`InverseCurveIterator`](crate::iterators::curve::InverseCurveIterator),
+    /// returning the gaps before, between and after the windows of the Curve, clipped to `limit`
+    #[must_use]
+    pub fn invert_up_to<O: CurveType>(&self, limit: TimeUnit) -> Curve<O> {
+        let mut windows = Vec::new();
+        let mut previous_end = TimeUnit::ZERO;
+
+        for window in &self.windows {
+            if limit <= previous_end {
+                break;
+            }
+
+            if previous_end < window.start {
+                windows.push(Window::new(
+                    previous_end,
+                    TimeUnit::min(window.start, limit),
+                ));
+            }
+
+            match window.end {
+                WindowEnd::Finite(end) => previous_end = end,
+                WindowEnd::Infinite => previous_end = limit,
+            }
+        }
+
+        if previous_end < limit {
+            windows.push(Window::new(previous_end, limit));
+        }
+
+        Curve { windows }
+    }
+
     /// compare the curve to a curve iterator
     /// consuming the iterator in the process
     pub fn eq_curve_iterator<CI: CurveIterator<CurveKind = T>>(&self, mut other: CI) -> bool {
@@ -130,12 +518,97 @@ impl<T: CurveType> Curve<T> {
             }
         }
     }
+
+    /// compare the curve to a curve iterator only up to `limit`
+    /// consuming the iterator only as far as necessary
+    ///
+    /// Windows of both sides are clipped at `limit`,
+    /// windows starting at or after `limit` are ignored
+    #[must_use]
+    pub fn eq_iterator_up_to<CI: CurveIterator<CurveKind = T>>(
+        &self,
+        mut other: CI,
+        limit: TimeUnit,
+    ) -> bool {
+        let clip = |window: &Window<T::WindowKind>| {
+            (window.start < limit)
+                .then(|| Window::<T::WindowKind>::new(window.start, window.end.min(limit.into())))
+        };
+
+        let mut windows = self.as_windows().iter().map_while(clip);
+        let mut other = core::iter::from_fn(move || other.next_window()).map_while(|w| clip(&w));
+
+        loop {
+            match (windows.next(), other.next()) {
+                (None, None) => break true,
+                (Some(_), None) | (None, Some(_)) => break false,
+                (Some(left), Some(right)) => {
+                    if left != right {
+                        break false;
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl<T: CurveType<WindowKind = Demand>> Curve<T> {
+    /// Determine how many leading windows fit into `capacity`
+    ///
+    /// Returns the exclusive index of the leading windows whose combined length stays within `capacity`
+    /// and the capacity remaining after them.
+    /// An infinite window never fits into the capacity.
+    ///
+    /// This is step (1) and (2) of Algorithm 2. of the paper, see [`Curve::partition`]
+    ///
+    /// # Example
+    /// ```
+    /// # use rta_for_fps_lib::curve::Curve;
+    /// # use rta_for_fps_lib::curve::curve_types::UnspecifiedCurve;
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// # use rta_for_fps_lib::window::{Demand, Window};
+    /// let curve = unsafe {
+    ///     Curve::<UnspecifiedCurve<Demand>>::from_windows_unchecked(vec![
+    ///         Window::new(0, 2),
+    ///         Window::new(3, 4),
+    ///         Window::new(5, 8),
+    ///     ])
+    /// };
+    ///
+    /// assert_eq!(curve.prefix_fitting(TimeUnit::from(4)), (2, TimeUnit::from(1)));
+    /// ```
+    #[must_use]
+    pub fn prefix_fitting(&self, capacity: TimeUnit) -> (usize, TimeUnit) {
+        // Note index is i+1 rather than i
+        // as 0 is used in case the first window is larges than the capacity
+        // meaning index is exclusive here rather than inclusive as in the paper
+        let (index, sum) = self
+            .windows
+            .iter()
+            .enumerate()
+            .scan(TimeUnit::ZERO, |acc, (index, window)| {
+                let length = window.length_finite()?;
+                *acc += length;
+                (*acc <= capacity).then(|| (index + 1, *acc))
+            })
+            .last()
+            .unwrap_or((0, TimeUnit::ZERO));
+
+        (index, capacity - sum)
+    }
+
     /// Partition the Curve as Defined by Algorithms 2. and 3. of the paper
     ///
     /// The implementation here deviates from the paper by returning an exclusive index while the paper uses an inclusive index
+    ///
+    /// Windows that end exactly at the budget boundary are fully included up to the index
+    /// for both Deferrable and Periodic Servers
+    ///
+    /// An infinite window never fits completely into the budget,
+    /// it is split with the head consuming the remaining budget and the tail being the infinite spill
+    ///
+    /// The budget of the group is its [`ServerProperties::group_capacity`],
+    /// a Background Server has no budget, so the whole Curve is included up to the index
     #[must_use]
     pub fn partition(
         &self,
@@ -151,45 +624,16 @@ impl<T: CurveType<WindowKind = Demand>> Curve<T> {
                 // this appears to be a mix-up between 0-based and 1-based indexing
                 // which is mixed throughout the paper
 
-                // Note index is i+1 rather than i
-                // as 0 is used in case the first window is larges than the server capacity
-                // meaning index is exclusive here rather than inclusive as in the paper
-
-                // (1)
-                let (index, sum) = self
-                    .windows
-                    .iter()
-                    .enumerate()
-                    .scan(TimeUnit::ZERO, |acc, (index, window)| {
-                        match window.length() {
-                            WindowEnd::Finite(length) => {
-                                *acc += length;
-                                (*acc <= server_properties.capacity).then(|| (index + 1, *acc))
-                            }
-                            WindowEnd::Infinite => None,
-                        }
-                    })
-                    .last()
-                    .unwrap_or((0, TimeUnit::ZERO));
-
-                // (2)
-                let remaining_capacity = server_properties.capacity - sum;
+                // (1) and (2)
+                let (index, remaining_capacity) =
+                    self.prefix_fitting(server_properties.group_capacity(group_index));
 
                 let (head, tail) = self.windows.get(index).map_or_else(
                     || (Window::empty(), Window::empty()),
                     |window| {
-                        if remaining_capacity > TimeUnit::ZERO {
-                            // we have remaining capacity and a window to fill the remaining budget
-                            let head_start = window.start;
-                            let tail_end = window.end;
-                            let split = head_start + remaining_capacity;
-                            let head = Window::new(head_start, Some(split));
-                            let tail = Window::new(split, tail_end);
-                            (head, tail)
-                        } else {
-                            // no capacity left set window as tail
-                            (Window::empty(), window.clone())
-                        }
+                        // split the window to fill the remaining budget,
+                        // with no capacity left this sets the whole window as the tail
+                        window.split_at(window.start + remaining_capacity)
                     },
                 );
 
@@ -199,18 +643,23 @@ impl<T: CurveType<WindowKind = Demand>> Curve<T> {
                 // Algorithm 3.
                 // (1)
 
-                let limit = group_index * server_properties.interval + server_properties.capacity;
+                // the start of the group plus the budget of the group
+                let limit = server_properties.group_start(group_index)
+                    + server_properties.group_capacity(group_index);
 
                 // Note index is i+1 rather than i,
                 // as 0 is used to indicate that the first window is already past the limit
                 // index need therefore be treated as exclusive rather than inclusive as in the paper
 
+                // Note a window ending exactly at the limit fits completely into the budget
+                // so it is included up to index, mirroring the `<=` used for Deferrable Servers
+
                 let index = self
                     .windows
                     .iter()
                     .enumerate()
-                    .filter_map(|(index, window)| (window.end < limit).then(|| index + 1))
-                    .last()
+                    .rev()
+                    .find_map(|(index, window)| (window.end <= limit).then(|| index + 1))
                     .unwrap_or(0);
 
                 // (2)
@@ -219,9 +668,7 @@ impl<T: CurveType<WindowKind = Demand>> Curve<T> {
                     |window| {
                         if window.start < limit && limit < window.end {
                             // window crosses the limit, split it at the limit
-                            let head = Window::new(window.start, limit);
-                            let tail = Window::new(limit, window.end);
-                            (head, tail)
+                            window.split_at(limit)
                         } else {
                             // Window won't be split as it does not contain the limit
                             // just set the window as the tail
@@ -232,6 +679,11 @@ impl<T: CurveType<WindowKind = Demand>> Curve<T> {
 
                 PartitionResult { index, head, tail }
             }
+            ServerKind::Background => PartitionResult {
+                index: self.windows.len(),
+                head: Window::empty(),
+                tail: Window::empty(),
+            },
         }
     }
 }
@@ -286,13 +738,116 @@ where
                     .into_iterator()
                     .for_each(|window| result.remaining_supply.windows.push(window)),
                 Delta::EndDemand(demand) => {
-                    demand.for_each(|window| result.remaining_demand.windows.push(window))
+                    demand.for_each(|window| result.remaining_demand.windows.push(window));
                 }
             }
         }
 
         result
     }
+
+    /// Partition the `CurveDeltaIterator` into the remaining supply, the overlap
+    /// and the remaining demand in a single pass, limiting all three curves to before `limit`
+    ///
+    /// Together the three curves reconstruct the input,
+    /// the remaining supply and the overlap cover exactly the supply before `limit`
+    /// and the overlap and remaining demand sum up to the demand.
+    /// As the supply is only consumed up to `limit` demand still waiting for supply at `limit`
+    /// is not part of the remaining demand.
+    ///
+    /// Unlike [`CurveDeltaIterator::collect_delta`] this terminates for infinite curves
+    #[must_use]
+    #[allow(clippy::type_complexity)]
+    pub fn collect_into_three(
+        self,
+        limit: TimeUnit,
+    ) -> (
+        Curve<UnspecifiedCurve<SW>>,
+        Curve<UnspecifiedCurve<Overlap<SW, DW>>>,
+        Curve<UnspecifiedCurve<DW>>,
+    )
+    where
+        Self: Iterator<Item = Delta<DW, SW, DI, SI>>,
+    {
+        /// push the part of `window` before `limit` returning whether it started before `limit`
+        fn push_limited<W>(
+            windows: &mut Vec<Window<W>>,
+            window: &Window<W>,
+            limit: TimeUnit,
+        ) -> bool {
+            let (head, _) = window.split_at(limit);
+            if head.is_empty() {
+                false
+            } else {
+                windows.push(head);
+                true
+            }
+        }
+
+        let mut remaining_supply = Vec::new();
+        let mut overlap = Vec::new();
+        let mut remaining_demand = Vec::new();
+
+        let mut delta_iter = self;
+
+        while let Some(delta) = delta_iter.next() {
+            let before_limit = match delta {
+                Delta::RemainingSupply(supply) => {
+                    push_limited(&mut remaining_supply, &supply, limit)
+                }
+                Delta::Overlap(window) => {
+                    let before_limit = push_limited(&mut overlap, &window, limit);
+
+                    // the remaining supply before an overlap is returned after the overlap,
+                    // so it may still start before limit
+                    if !before_limit {
+                        if let Some(Delta::RemainingSupply(supply)) = delta_iter.next() {
+                            push_limited(&mut remaining_supply, &supply, limit);
+                        }
+                    }
+
+                    before_limit
+                }
+                Delta::RemainingDemand(demand) => {
+                    push_limited(&mut remaining_demand, &demand, limit)
+                }
+                Delta::EndSupply(supply) => {
+                    for window in supply.into_iterator() {
+                        if !push_limited(&mut remaining_supply, &window, limit) {
+                            break;
+                        }
+                    }
+                    true
+                }
+                Delta::EndDemand(demand) => {
+                    for window in demand {
+                        if !push_limited(&mut remaining_demand, &window, limit) {
+                            break;
+                        }
+                    }
+                    true
+                }
+            };
+
+            // the supply is consumed in order and the remaining demand is only returned
+            // once the supply ran out, so once a window starts at or after limit
+            // there is nothing left before limit
+            if !before_limit {
+                break;
+            }
+        }
+
+        // Safety
+        // each kind of window is returned in order and non-overlapping by the delta,
+        // merging adjacent windows establishes the Curve invariants
+        unsafe {
+            (
+                Curve::from_windows_unchecked(remaining_supply).merge_adjacent(),
+                Curve::from_windows_unchecked(overlap).merge_adjacent(),
+                Curve::from_windows_unchecked(remaining_demand).merge_adjacent(),
+            )
+        }
+    }
 }
 
 /// Return Type for [`Curve::partition`](Curve::partition)
@@ -5,8 +5,9 @@ use core::marker::PhantomData;
 
 use crate::seal::Seal;
 use crate::server::{
-    ActualServerExecution, AggregatedServerDemand, ConstrainedServerDemand,
-    HigherPriorityServerDemand, HigherPriorityServerExecution, UnconstrainedServerExecution,
+    ActualServerExecution, AggregatedServerDemand, AvailableServerExecution,
+    ConstrainedServerDemand, HigherPriorityServerDemand, HigherPriorityServerExecution, ServerIdle,
+    UnconstrainedServerExecution,
 };
 use crate::task::curve_types::{
     ActualTaskExecution, AvailableTaskExecution, HigherPriorityTaskDemand, TaskDemand,
@@ -44,10 +45,18 @@ impl CurveType for UnconstrainedServerExecution {
     type WindowKind = Overlap<Supply, Demand>;
 }
 
+impl CurveType for AvailableServerExecution {
+    type WindowKind = <UnconstrainedServerExecution as CurveType>::WindowKind;
+}
+
 impl CurveType for ActualServerExecution {
     type WindowKind = Overlap<<UnconstrainedServerExecution as CurveType>::WindowKind, Demand>;
 }
 
+impl CurveType for ServerIdle {
+    type WindowKind = Supply;
+}
+
 impl CurveType for TaskDemand {
     type WindowKind = Demand;
 }
@@ -0,0 +1,301 @@
+//! Module for reading Task sets from external formats
+//!
+//! Requires the `std` feature, reading JSON additionally requires the `json` feature
+
+use core::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::string::String;
+use std::vec::Vec;
+
+use crate::task::{Task, TaskError};
+use crate::time::UnitNumber;
+
+#[cfg(feature = "json")]
+use crate::server::{OwnedServer, ServerKind, ServerProperties};
+#[cfg(feature = "json")]
+use crate::system::OwnedSystem;
+#[cfg(feature = "json")]
+use crate::time::TimeUnit;
+
+/// Error while parsing a Task set
+#[derive(Debug)]
+pub enum ParseError {
+    /// Reading the input failed
+    Io(std::io::Error),
+    /// A row did not consist of exactly three fields
+    FieldCount {
+        /// the 1-based line number of the row
+        line: usize,
+        /// the number of fields found in the row
+        found: usize,
+    },
+    /// A field could not be parsed as a number
+    InvalidNumber {
+        /// the 1-based line number of the row
+        line: usize,
+        /// the name of the field
+        field: &'static str,
+        /// the content of the field
+        value: String,
+    },
+    /// The fields of a row don't describe a valid Task
+    InvalidTask {
+        /// the 1-based line number of the row
+        line: usize,
+        /// the reason the Task is invalid
+        error: TaskError,
+    },
+    /// The input is not a well-formed JSON System description
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+    /// A Task of a JSON System description is not valid
+    InvalidServerTask {
+        /// the index of the Server of the Task
+        server: usize,
+        /// the index of the Task within its Server
+        task: usize,
+        /// the reason the Task is invalid
+        error: TaskError,
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseError::Io(error) => write!(f, "Failed to read input: {error}"),
+            ParseError::FieldCount { line, found } => write!(
+                f,
+                "Line {line}: expected 3 fields (demand,interval,offset), found {found}"
+            ),
+            ParseError::InvalidNumber { line, field, value } => {
+                write!(f, "Line {line}: invalid {field} {value:?}")
+            }
+            ParseError::InvalidTask { line, error } => write!(f, "Line {line}: {error}"),
+            #[cfg(feature = "json")]
+            ParseError::Json(error) => write!(f, "Invalid System description: {error}"),
+            ParseError::InvalidServerTask {
+                server,
+                task,
+                error,
+            } => write!(f, "Server {server}, Task {task}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Io(error) => Some(error),
+            #[cfg(feature = "json")]
+            ParseError::Json(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ParseError {
+    fn from(error: std::io::Error) -> Self {
+        ParseError::Io(error)
+    }
+}
+
+/// The names of the CSV fields in order
+const FIELDS: [&str; 3] = ["demand", "interval", "offset"];
+
+/// Parse Tasks from CSV rows of `demand,interval,offset`, in order of their priority
+///
+/// Empty lines and lines starting with `#` are ignored,
+/// as is a first row consisting of the field names
+///
+/// ```
+/// # use rta_for_fps_lib::io::parse_tasks_csv;
+/// # use rta_for_fps_lib::time::TimeUnit;
+/// let csv = "demand,interval,offset\n1,4,0\n2,8,3\n";
+///
+/// let tasks = parse_tasks_csv(csv.as_bytes()).unwrap();
+///
+/// assert_eq!(tasks.len(), 2);
+/// assert_eq!(tasks[1].offset, TimeUnit::from(3));
+/// ```
+///
+/// # Errors
+/// When reading fails or a row is malformed, reporting the line of the malformed row
+pub fn parse_tasks_csv<R: BufRead>(reader: R) -> Result<Vec<Task>, ParseError> {
+    let mut tasks = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line?;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<_> = line.split(',').map(str::trim).collect();
+
+        if fields.len() != FIELDS.len() {
+            return Err(ParseError::FieldCount {
+                line: line_number,
+                found: fields.len(),
+            });
+        }
+
+        if tasks.is_empty() && fields == FIELDS {
+            // header
+            continue;
+        }
+
+        let mut numbers = [0; 3];
+        for ((number, field), value) in numbers.iter_mut().zip(FIELDS).zip(&fields) {
+            *number = value
+                .parse::<UnitNumber>()
+                .map_err(|_| ParseError::InvalidNumber {
+                    line: line_number,
+                    field,
+                    value: String::from(*value),
+                })?;
+        }
+
+        let [demand, interval, offset] = numbers;
+
+        let task =
+            Task::try_new(demand, interval, offset).map_err(|error| ParseError::InvalidTask {
+                line: line_number,
+                error,
+            })?;
+
+        tasks.push(task);
+    }
+
+    Ok(tasks)
+}
+
+/// The JSON representation of a System read by [`parse_system_json`]
+#[cfg(feature = "json")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SystemJson {
+    /// the Servers indexed by their priority
+    servers: Vec<ServerJson>,
+}
+
+/// The JSON representation of a Server read by [`parse_system_json`]
+#[cfg(feature = "json")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ServerJson {
+    /// see [`ServerProperties::capacity`]
+    capacity: UnitNumber,
+    /// see [`ServerProperties::interval`]
+    interval: UnitNumber,
+    /// see [`ServerProperties::server_type`]
+    kind: ServerKindJson,
+    /// the Tasks indexed by their priority
+    tasks: Vec<TaskJson>,
+}
+
+/// The JSON representation of a [`ServerKind`]
+#[cfg(feature = "json")]
+#[derive(serde::Deserialize)]
+enum ServerKindJson {
+    /// see [`ServerKind::Deferrable`]
+    Deferrable,
+    /// see [`ServerKind::Periodic`]
+    Periodic,
+    /// see [`ServerKind::Background`]
+    Background,
+}
+
+/// The JSON representation of a Task read by [`parse_system_json`]
+#[cfg(feature = "json")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TaskJson {
+    /// see [`Task::demand`]
+    demand: UnitNumber,
+    /// see [`Task::interval`]
+    interval: UnitNumber,
+    /// see [`Task::offset`], defaults to zero
+    #[serde(default)]
+    offset: UnitNumber,
+}
+
+/// Parse a System from its JSON description, Servers and their Tasks in order of their priority
+///
+/// Each Server has a `capacity`, an `interval`, a `kind` of `Deferrable`, `Periodic` or `Background`
+/// and its `tasks`, each Task has a `demand`, an `interval` and an optional `offset`
+///
+/// ```
+/// # use rta_for_fps_lib::io::parse_system_json;
+/// # use rta_for_fps_lib::time::TimeUnit;
+/// let json = r#"{
+///     "servers": [
+///         { "capacity": 3, "interval": 10, "kind": "Deferrable", "tasks": [
+///             { "demand": 1, "interval": 4 }
+///         ] },
+///         { "capacity": 2, "interval": 4, "kind": "Periodic", "tasks": [
+///             { "demand": 1, "interval": 5 },
+///             { "demand": 2, "interval": 8, "offset": 3 }
+///         ] }
+///     ]
+/// }"#;
+///
+/// let system = parse_system_json(json.as_bytes()).unwrap();
+///
+/// assert_eq!(system.view().as_system().as_servers()[1].as_tasks()[1].offset, TimeUnit::from(3));
+/// ```
+///
+/// # Errors
+/// When reading fails, the input is malformed, reporting its line and column,
+/// or a Task is invalid, reporting its Server and index
+#[cfg(feature = "json")]
+pub fn parse_system_json<R: std::io::Read>(reader: R) -> Result<OwnedSystem, ParseError> {
+    let system: SystemJson = serde_json::from_reader(reader).map_err(|error| {
+        if error.is_io() {
+            ParseError::Io(error.into())
+        } else {
+            ParseError::Json(error)
+        }
+    })?;
+
+    let servers = system
+        .servers
+        .into_iter()
+        .enumerate()
+        .map(|(server_index, server)| {
+            let tasks = server
+                .tasks
+                .into_iter()
+                .enumerate()
+                .map(|(task_index, task)| {
+                    Task::try_new(task.demand, task.interval, task.offset).map_err(|error| {
+                        ParseError::InvalidServerTask {
+                            server: server_index,
+                            task: task_index,
+                            error,
+                        }
+                    })
+                })
+                .collect::<Result<_, ParseError>>()?;
+
+            let server_type = match server.kind {
+                ServerKindJson::Deferrable => ServerKind::Deferrable,
+                ServerKindJson::Periodic => ServerKind::Periodic,
+                ServerKindJson::Background => ServerKind::Background,
+            };
+
+            Ok(OwnedServer {
+                tasks,
+                properties: ServerProperties {
+                    capacity: TimeUnit::from(server.capacity),
+                    interval: TimeUnit::from(server.interval),
+                    server_type,
+                    phase: TimeUnit::ZERO,
+                },
+            })
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok(OwnedSystem::new(servers))
+}
@@ -1,16 +1,19 @@
 //! Module for the Iterator based implementation
 
 use alloc::boxed::Box;
-use core::fmt::Debug;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
 use core::iter::{Empty, Fuse, TakeWhile};
 use core::marker::PhantomData;
 
 use crate::curve::curve_types::{CurveType, UnspecifiedCurve};
 use crate::iterators::curve::FromCurveIterator;
 use crate::iterators::join::JoinAdjacentIterator;
+use crate::time::TimeUnit;
 use crate::window::window_types::WindowType;
-use crate::window::Window;
+use crate::window::{Window, WindowEnd};
 
+pub mod cache;
 pub mod curve;
 pub mod join;
 pub mod peek;
@@ -41,7 +44,74 @@ pub trait CurveIterator: Debug {
         R::from_curve_iter(self)
     }
 
+    /// collect the start and end of every window,
+    /// e.g. for quick assertions or plotting without carrying the curve type
+    ///
+    /// Unlike [`CurveIterator::collect_curve`] adjacent windows are not merged
+    ///
+    /// # Example
+    /// ```
+    /// # use rta_for_fps_lib::iterators::CurveIterator;
+    /// # use rta_for_fps_lib::task::Task;
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// # use rta_for_fps_lib::window::WindowEnd;
+    /// let pairs = Task::new(1, 4, 0)
+    ///     .into_iter()
+    ///     .take_while_curve(|window| window.start < TimeUnit::from(8))
+    ///     .collect_pairs();
+    ///
+    /// assert_eq!(
+    ///     pairs,
+    ///     vec![
+    ///         (TimeUnit::from(0), WindowEnd::Finite(TimeUnit::from(1))),
+    ///         (TimeUnit::from(4), WindowEnd::Finite(TimeUnit::from(5))),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    fn collect_pairs(self) -> Vec<(TimeUnit, WindowEnd)>
+    where
+        Self: Sized,
+    {
+        self.into_iterator()
+            .map(|window| (window.start, window.end))
+            .collect()
+    }
+
     /// reclassify a `CurveIterator`
+    ///
+    /// Only changes the Curve type, the window kind stays the same
+    ///
+    /// # Example
+    /// ```
+    /// # use rta_for_fps_lib::curve::Curve;
+    /// # use rta_for_fps_lib::curve::curve_types::UnspecifiedCurve;
+    /// # use rta_for_fps_lib::iterators::CurveIterator;
+    /// # use rta_for_fps_lib::task::Task;
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// # use rta_for_fps_lib::window::Demand;
+    /// // a TaskDemand curve viewed as a plain Demand curve
+    /// let demand: Curve<UnspecifiedCurve<Demand>> = Task::new(1, 4, 0)
+    ///     .into_iter()
+    ///     .take_while_curve(|window| window.start < TimeUnit::from(8))
+    ///     .reclassify()
+    ///     .collect_curve();
+    ///
+    /// assert_eq!(demand.into_windows().len(), 2);
+    /// ```
+    ///
+    /// Changing the window kind is rejected
+    /// ```compile_fail,E0599
+    /// # use rta_for_fps_lib::curve::curve_types::UnspecifiedCurve;
+    /// # use rta_for_fps_lib::iterators::CurveIterator;
+    /// # use rta_for_fps_lib::task::Task;
+    /// # use rta_for_fps_lib::window::Supply;
+    /// // Task Demand has Demand windows, not Supply windows
+    /// let _ = Task::new(1, 4, 0)
+    ///     .into_iter()
+    ///     .reclassify::<UnspecifiedCurve<Supply>>()
+    ///     .next_window();
+    /// ```
     #[must_use]
     fn reclassify<O>(self) -> ReclassifyIterator<Self, O>
     where
@@ -68,6 +138,9 @@ pub trait CurveIterator: Debug {
     }
 
     /// Basically [`core::iter::Iterator::take_while`] but for `CurveIterator`
+    ///
+    /// Once `fun` returns false the wrapped `CurveIterator` is not polled again,
+    /// so expensive infinite `CurveIterator`s are not advanced further by subsequent adapters like [`CurveIterator::normalize`]
     fn take_while_curve<F>(self, fun: F) -> TakeWhile<CurveIteratorIterator<Self>, F>
     where
         Self: Sized,
@@ -84,6 +157,240 @@ pub trait CurveIterator: Debug {
         self.into_iterator().fuse()
     }
 
+    /// Basically [`core::iter::Iterator::inspect`] but for `CurveIterator`
+    ///
+    /// Calls `fun` on every window passing through, useful for debugging
+    fn inspect_windows<F>(self, fun: F) -> InspectIterator<Self, F>
+    where
+        Self: Sized,
+        F: for<'a> FnMut(&'a Window<<Self::CurveKind as CurveType>::WindowKind>),
+    {
+        InspectIterator { iter: self, fun }
+    }
+
+    /// Basically [`core::iter::Iterator::flat_map`] but for `CurveIterator`
+    ///
+    /// Replaces every window by the windows returned by `fun` for it,
+    /// e.g. splitting execution windows to model self-suspension.
+    ///
+    /// The returned windows are validated as they pass through,
+    /// see [`FlatMapWindowsIterator`] for when it panics
+    fn flat_map_windows<O, F, J>(self, fun: F) -> FlatMapWindowsIterator<Self, O, F, J::IntoIter>
+    where
+        Self: Sized,
+        O: CurveType,
+        F: FnMut(Window<<Self::CurveKind as CurveType>::WindowKind>) -> J,
+        J: IntoIterator<Item = Window<O::WindowKind>>,
+    {
+        FlatMapWindowsIterator {
+            iter: self,
+            fun,
+            current: None,
+            previous_end: None,
+            curve_type: PhantomData,
+        }
+    }
+
+    /// Check the `CurveIterator` invariants for every returned window in debug builds,
+    /// panicking with the offending windows when a window is empty
+    /// or starts before the end of the previous window
+    ///
+    /// In release builds the windows are passed through unchecked
+    fn debug_check_invariants(self) -> DebugCheckIterator<Self>
+    where
+        Self: Sized,
+    {
+        DebugCheckIterator {
+            iter: self,
+            #[cfg(debug_assertions)]
+            previous: None,
+        }
+    }
+
+    /// Take windows until their combined length reaches `capacity`
+    ///
+    /// The last window is truncated so that the combined length is exactly `capacity`,
+    /// unless the `CurveIterator` runs out of windows before
+    fn take_up_to_capacity(self, capacity: TimeUnit) -> TakeCapacityIterator<Self>
+    where
+        Self: Sized,
+    {
+        TakeCapacityIterator {
+            iter: self,
+            remaining: capacity,
+        }
+    }
+
+    /// Clamp the curve to end at `horizon`
+    ///
+    /// Windows starting at or after `horizon` are dropped
+    /// and a window straddling `horizon`, including an infinite window, is truncated to end at `horizon`.
+    /// Unlike [`CurveIterator::take_while_curve`] with a predicate on the window end
+    /// the straddling window is kept, so the capacity up to `horizon` is preserved
+    fn clamp_to(self, horizon: TimeUnit) -> ClampIterator<Self>
+    where
+        Self: Sized,
+    {
+        ClampIterator {
+            iter: self,
+            horizon,
+            done: false,
+        }
+    }
+
+    /// Count the windows that start before `limit`
+    /// consuming the iterator up to the first window starting at or after `limit`
+    ///
+    /// A window straddling `limit` is counted as one window
+    fn count_windows_up_to(mut self, limit: TimeUnit) -> usize
+    where
+        Self: Sized,
+    {
+        let mut count = 0;
+        while let Some(window) = self.next_window() {
+            if window.start >= limit {
+                break;
+            }
+            count += 1;
+        }
+        count
+    }
+
+    /// Sum the lengths of the windows while `predicate` holds,
+    /// consuming the iterator up to and including the first window for which it fails
+    ///
+    /// # Panics
+    /// When an infinite window satisfies `predicate`,
+    /// as the sum would be infinite
+    fn sum_length_while<F>(mut self, mut predicate: F) -> TimeUnit
+    where
+        Self: Sized,
+        F: FnMut(&Window<<Self::CurveKind as CurveType>::WindowKind>) -> bool,
+    {
+        let mut sum = TimeUnit::ZERO;
+        while let Some(window) = self.next_window() {
+            if !predicate(&window) {
+                break;
+            }
+            sum += window.length_or_panic();
+        }
+        sum
+    }
+
+    /// Check whether the windows of `self` and `other` overlap non-trivially
+    /// before `up_to`, adjacent windows merely touching do not count as overlap
+    ///
+    /// Both `CurveIterator`s are advanced in lockstep and only as far as needed,
+    /// so this returns early on the first overlap and works for infinite curves
+    fn windows_overlap_with<O: CurveIterator>(self, other: O, up_to: TimeUnit) -> bool
+    where
+        Self: Sized,
+    {
+        first_window_overlap(self, other, up_to).is_some()
+    }
+
+    /// Count how often execution switches between `self` and `other`
+    /// for the windows starting before `up_to`, e.g. the actual execution of two servers
+    ///
+    /// The windows of both `CurveIterator`s are visited in order of their start,
+    /// visiting a window of one after a window of the other counts as a switch.
+    /// Windows of `self` and `other` overlapping non-trivially are counted separately,
+    /// as execution curves of different servers should not overlap
+    fn interleave_count<O: CurveIterator>(mut self, mut other: O, up_to: TimeUnit) -> Interleaving
+    where
+        Self: Sized,
+    {
+        let mut left = self.next_window().filter(|window| window.start < up_to);
+        let mut right = other.next_window().filter(|window| window.start < up_to);
+
+        // the last visited window of each side,
+        // only these can overlap with the next visited window of the other side
+        let mut last_left: Option<(TimeUnit, WindowEnd)> = None;
+        let mut last_right: Option<(TimeUnit, WindowEnd)> = None;
+        let mut last_was_left = None;
+
+        let mut interleaving = Interleaving {
+            switches: 0,
+            overlaps: 0,
+        };
+
+        loop {
+            let visit_left = match (&left, &right) {
+                (None, None) => break,
+                (Some(l), Some(r)) => l.start <= r.start,
+                (l, _) => l.is_some(),
+            };
+
+            let (visited, last_other) = if visit_left {
+                last_left = left.as_ref().map(|window| (window.start, window.end));
+                left = self.next_window().filter(|window| window.start < up_to);
+                (last_left, last_right)
+            } else {
+                last_right = right.as_ref().map(|window| (window.start, window.end));
+                right = other.next_window().filter(|window| window.start < up_to);
+                (last_right, last_left)
+            };
+
+            if last_was_left == Some(!visit_left) {
+                interleaving.switches += 1;
+            }
+            last_was_left = Some(visit_left);
+
+            if let (Some((start, end)), Some((other_start, other_end))) = (visited, last_other) {
+                if start < other_end && other_start < end {
+                    interleaving.overlaps += 1;
+                }
+            }
+        }
+
+        interleaving
+    }
+
+    /// Zip the cumulative functions of `self` and `other`,
+    /// the cumulative function of a curve at time `t` being the combined length of its windows before `t`
+    ///
+    /// The resulting Iterator yields `(time, cumulative_self, cumulative_other)`
+    /// for every start and end of a window of either curve in ascending order,
+    /// only advancing both `CurveIterator`s as far as needed.
+    /// The end of an infinite window is never reached and therefore not yielded.
+    ///
+    /// # Example
+    /// ```
+    /// # use rta_for_fps_lib::curve::Curve;
+    /// # use rta_for_fps_lib::curve::curve_types::UnspecifiedCurve;
+    /// # use rta_for_fps_lib::iterators::CurveIterator;
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// # use rta_for_fps_lib::window::{Demand, Window};
+    /// let a = Curve::<UnspecifiedCurve<Demand>>::new(Window::new(0, 4));
+    /// let b = Curve::<UnspecifiedCurve<Demand>>::new(Window::new(2, 4));
+    ///
+    /// let max_distance = a
+    ///     .into_iter()
+    ///     .zip_cumulative(b.into_iter())
+    ///     .map(|(_, a, b)| a - b)
+    ///     .max();
+    ///
+    /// assert_eq!(max_distance, Some(TimeUnit::from(2)));
+    /// ```
+    fn zip_cumulative<O: CurveIterator>(self, other: O) -> ZipCumulative<Self, O>
+    where
+        Self: Sized,
+    {
+        ZipCumulative {
+            left: Cumulative::new(self),
+            right: Cumulative::new(other),
+            time: None,
+        }
+    }
+
+    /// Box the `CurveIterator` into a [`BoxedCurveIterator`] to erase its type
+    fn boxed<'a>(self) -> BoxedCurveIterator<'a, Self::CurveKind>
+    where
+        Self: Sized + 'a,
+    {
+        BoxedCurveIterator::new(self)
+    }
+
     /// Wrap the `CurveIterator` to allow usage of standart Iterator adapters
     fn into_iterator(self) -> CurveIteratorIterator<Self>
     where
@@ -93,10 +400,163 @@ pub trait CurveIterator: Debug {
     }
 }
 
-/// `CurveIterator` wrapper to change the Curve type to any compatibly `CurveType`
+/// The result of [`CurveIterator::interleave_count`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interleaving {
+    /// how often execution switched from one curve to the other
+    pub switches: usize,
+    /// the number of pairs of windows of the two curves overlapping non-trivially
+    pub overlaps: usize,
+}
+
+/// Iterator over the cumulative functions of two curves
+///
+/// See [`CurveIterator::zip_cumulative`]
+#[derive(Debug)]
+pub struct ZipCumulative<L: CurveIterator, R: CurveIterator> {
+    /// the cumulative function of the first curve
+    left: Cumulative<L>,
+    /// the cumulative function of the second curve
+    right: Cumulative<R>,
+    /// the last yielded time
+    time: Option<TimeUnit>,
+}
+
+impl<L: CurveIterator + Clone, R: CurveIterator + Clone> Clone for ZipCumulative<L, R> {
+    fn clone(&self) -> Self {
+        ZipCumulative {
+            left: self.left.clone(),
+            right: self.right.clone(),
+            time: self.time,
+        }
+    }
+}
+
+impl<L: CurveIterator, R: CurveIterator> Iterator for ZipCumulative<L, R> {
+    type Item = (TimeUnit, TimeUnit, TimeUnit);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let left = self.left.next_boundary(self.time);
+        let right = self.right.next_boundary(self.time);
+
+        let time = match (left, right) {
+            (None, None) => return None,
+            (Some(left), Some(right)) => left.min(right),
+            (Some(time), None) | (None, Some(time)) => time,
+        };
+
+        self.time = Some(time);
+
+        Some((
+            time,
+            self.left.advance_to(time),
+            self.right.advance_to(time),
+        ))
+    }
+}
+
+/// The cumulative function of a curve, used by [`ZipCumulative`]
+#[derive(Debug)]
+struct Cumulative<I: CurveIterator> {
+    /// the remaining windows of the curve
+    iter: I,
+    /// the window currently being accounted
+    current: Option<Window<<I::CurveKind as CurveType>::WindowKind>>,
+    /// the combined length of the windows already completely accounted
+    completed: TimeUnit,
+}
+
+impl<I: CurveIterator + Clone> Clone for Cumulative<I> {
+    fn clone(&self) -> Self {
+        Cumulative {
+            iter: self.iter.clone(),
+            current: self.current.clone(),
+            completed: self.completed,
+        }
+    }
+}
+
+impl<I: CurveIterator> Cumulative<I> {
+    /// Create a new cumulative function for the curve of `iter`
+    const fn new(iter: I) -> Self {
+        Cumulative {
+            iter,
+            current: None,
+            completed: TimeUnit::ZERO,
+        }
+    }
+
+    /// The first start or end of a window after `time`, the first start for no `time`
+    fn next_boundary(&mut self, time: Option<TimeUnit>) -> Option<TimeUnit> {
+        if self.current.is_none() {
+            self.current = self.iter.next_window();
+        }
+
+        self.current.as_ref().and_then(|window| {
+            if time.map_or(true, |time| window.start > time) {
+                Some(window.start)
+            } else {
+                window.end.as_finite()
+            }
+        })
+    }
+
+    /// Advance to `time`, which must not lie beyond the end of the current window,
+    /// and return the value of the cumulative function at `time`
+    fn advance_to(&mut self, time: TimeUnit) -> TimeUnit {
+        if let Some(window) = self.current.as_ref() {
+            if window.end == time {
+                self.completed += window.length_or_panic();
+                self.current = None;
+            }
+        }
+
+        self.completed
+            + self
+                .current
+                .as_ref()
+                .filter(|window| window.start < time)
+                .map_or(TimeUnit::ZERO, |window| time - window.start)
+    }
+}
+
+/// Find the first pair of windows of `left` and `right` that overlap non-trivially before `up_to`,
+/// see [`CurveIterator::windows_overlap_with`]
+#[allow(clippy::type_complexity)]
+pub(crate) fn first_window_overlap<L: CurveIterator, R: CurveIterator>(
+    mut left: L,
+    mut right: R,
+    up_to: TimeUnit,
+) -> Option<(
+    Window<<L::CurveKind as CurveType>::WindowKind>,
+    Window<<R::CurveKind as CurveType>::WindowKind>,
+)> {
+    let mut left_window = left.next_window();
+    let mut right_window = right.next_window();
+
+    while let (Some(l), Some(r)) = (left_window.as_ref(), right_window.as_ref()) {
+        if l.start >= up_to || r.start >= up_to {
+            break;
+        }
+
+        if l.start < r.end && r.start < l.end {
+            return left_window.zip(right_window);
+        }
+
+        if l.end <= r.end {
+            left_window = left.next_window();
+        } else {
+            right_window = right.next_window();
+        }
+    }
+
+    None
+}
+
+/// `CurveIterator` wrapper to change the Curve type to any `CurveType` with the same window kind
 #[derive(Debug)]
 pub struct ReclassifyIterator<I, O> {
-    /// the wrapped CurveIterator
+    /// the wrapped `CurveIterator`
     iter: I,
     /// The output curve type and `CurveType`
     phantom: PhantomData<O>,
@@ -112,6 +572,61 @@ impl<I: Clone, O> Clone for ReclassifyIterator<I, O> {
 }
 
 impl<I, O> CurveIterator for ReclassifyIterator<I, O>
+where
+    I: CurveIterator,
+    O: CurveType<WindowKind = <I::CurveKind as CurveType>::WindowKind>,
+{
+    type CurveKind = O;
+
+    fn next_window(&mut self) -> Option<Window<O::WindowKind>> {
+        self.iter.next_window()
+    }
+}
+
+/// `CurveIterator` wrapper to change the Curve type to any `CurveType`,
+/// including the window kind
+///
+/// Only constructed within the crate where changing the window kind is meaningful,
+/// e.g. for using the actual execution of higher priority servers as supply,
+/// outside the crate use [`CurveIterator::reclassify`] or [`AggregationIterator::aggregate_into`](crate::iterators::curve::AggregationIterator::aggregate_into)
+///
+/// ```compile_fail,E0624
+/// # use rta_for_fps_lib::curve::curve_types::UnspecifiedCurve;
+/// # use rta_for_fps_lib::iterators::ReinterpretIterator;
+/// # use rta_for_fps_lib::task::Task;
+/// # use rta_for_fps_lib::window::Supply;
+/// let _ = ReinterpretIterator::<_, UnspecifiedCurve<Supply>>::new(Task::new(1, 4, 0).into_iter());
+/// ```
+#[derive(Debug)]
+pub struct ReinterpretIterator<I, O> {
+    /// the wrapped `CurveIterator`
+    iter: I,
+    /// The output curve type and `CurveType`
+    phantom: PhantomData<O>,
+}
+
+impl<I, O> ReinterpretIterator<I, O> {
+    /// reinterpret a `CurveIterator` as a Curve type with a different window kind
+    ///
+    /// Unlike [`CurveIterator::reclassify`] this also changes the window kind
+    pub(crate) const fn new(iter: I) -> Self {
+        ReinterpretIterator {
+            iter,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I: Clone, O> Clone for ReinterpretIterator<I, O> {
+    fn clone(&self) -> Self {
+        ReinterpretIterator {
+            iter: self.iter.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, O> CurveIterator for ReinterpretIterator<I, O>
 where
     I: CurveIterator,
     O: CurveType,
@@ -123,6 +638,242 @@ where
     }
 }
 
+/// `CurveIterator` wrapper calling a function on every window
+///
+/// See [`CurveIterator::inspect_windows`]
+#[derive(Clone)]
+pub struct InspectIterator<I, F> {
+    /// the wrapped `CurveIterator`
+    iter: I,
+    /// the function to call on every window
+    fun: F,
+}
+
+impl<I: Debug, F> Debug for InspectIterator<I, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("InspectIterator")
+            .field("iter", &self.iter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F> CurveIterator for InspectIterator<I, F>
+where
+    I: CurveIterator,
+    F: for<'a> FnMut(&'a Window<<I::CurveKind as CurveType>::WindowKind>),
+{
+    type CurveKind = I::CurveKind;
+
+    fn next_window(&mut self) -> Option<Window<<Self::CurveKind as CurveType>::WindowKind>> {
+        let window = self.iter.next_window();
+        if let Some(window) = window.as_ref() {
+            (self.fun)(window);
+        }
+        window
+    }
+}
+
+/// `CurveIterator` wrapper replacing every window by the windows returned by a function
+///
+/// See [`CurveIterator::flat_map_windows`]
+///
+/// # Panics
+/// When the function returns an empty window
+/// or a window starting before the end of a previously returned window,
+/// as the output would violate the `CurveIterator` invariants
+pub struct FlatMapWindowsIterator<I, O, F, J> {
+    /// the wrapped `CurveIterator`
+    iter: I,
+    /// the function to expand every window with
+    fun: F,
+    /// the remaining windows of the last expanded window
+    current: Option<J>,
+    /// the end of the previously returned window
+    previous_end: Option<WindowEnd>,
+    /// the `CurveType` this produces
+    curve_type: PhantomData<O>,
+}
+
+impl<I: Clone, O, F: Clone, J: Clone> Clone for FlatMapWindowsIterator<I, O, F, J> {
+    fn clone(&self) -> Self {
+        FlatMapWindowsIterator {
+            iter: self.iter.clone(),
+            fun: self.fun.clone(),
+            current: self.current.clone(),
+            previous_end: self.previous_end,
+            curve_type: PhantomData,
+        }
+    }
+}
+
+impl<I: Debug, O, F, J> Debug for FlatMapWindowsIterator<I, O, F, J> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FlatMapWindowsIterator")
+            .field("iter", &self.iter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, O, F, J, R> CurveIterator for FlatMapWindowsIterator<I, O, F, J>
+where
+    I: CurveIterator,
+    O: CurveType,
+    F: FnMut(Window<<I::CurveKind as CurveType>::WindowKind>) -> R,
+    R: IntoIterator<Item = Window<O::WindowKind>, IntoIter = J>,
+    J: Iterator<Item = Window<O::WindowKind>>,
+{
+    type CurveKind = O;
+
+    fn next_window(&mut self) -> Option<Window<<Self::CurveKind as CurveType>::WindowKind>> {
+        loop {
+            if let Some(window) = self.current.as_mut().and_then(Iterator::next) {
+                assert!(
+                    !window.is_empty(),
+                    "flat_map_windows produced an empty window: {}",
+                    window
+                );
+
+                if let Some(previous_end) = self.previous_end {
+                    assert!(
+                        previous_end <= window.start,
+                        "flat_map_windows produced a window starting before the end of the previous window:\nprevious end: {}\nnext: {}",
+                        previous_end,
+                        window
+                    );
+                }
+
+                self.previous_end = Some(window.end);
+                break Some(window);
+            }
+
+            let window = self.iter.next_window()?;
+            self.current = Some((self.fun)(window).into_iter());
+        }
+    }
+}
+
+/// `CurveIterator` wrapper asserting the `CurveIterator` invariants in debug builds
+///
+/// See [`CurveIterator::debug_check_invariants`]
+#[derive(Debug)]
+pub struct DebugCheckIterator<I: CurveIterator> {
+    /// the wrapped `CurveIterator`
+    iter: I,
+    /// the previously returned window
+    #[cfg(debug_assertions)]
+    previous: Option<Window<<I::CurveKind as CurveType>::WindowKind>>,
+}
+
+impl<I: CurveIterator + Clone> Clone for DebugCheckIterator<I> {
+    fn clone(&self) -> Self {
+        DebugCheckIterator {
+            iter: self.iter.clone(),
+            #[cfg(debug_assertions)]
+            previous: self.previous.clone(),
+        }
+    }
+}
+
+impl<I: CurveIterator> CurveIterator for DebugCheckIterator<I> {
+    type CurveKind = I::CurveKind;
+
+    fn next_window(&mut self) -> Option<Window<<Self::CurveKind as CurveType>::WindowKind>> {
+        let window = self.iter.next_window();
+
+        #[cfg(debug_assertions)]
+        if let Some(window) = window.as_ref() {
+            assert!(
+                !window.is_empty(),
+                "CurveIterator returned an empty window: {}",
+                window
+            );
+
+            if let Some(previous) = self.previous.as_ref() {
+                assert!(
+                    previous.end <= window.start,
+                    "CurveIterator returned a window starting before the end of the previous window:\nprevious: {}\nnext: {}",
+                    previous,
+                    window
+                );
+            }
+
+            self.previous = Some(window.clone());
+        }
+
+        window
+    }
+}
+
+/// `CurveIterator` wrapper ending the curve once a capacity is reached
+///
+/// See [`CurveIterator::take_up_to_capacity`]
+#[derive(Debug, Clone)]
+pub struct TakeCapacityIterator<I> {
+    /// the wrapped `CurveIterator`
+    iter: I,
+    /// the capacity still to be taken
+    remaining: TimeUnit,
+}
+
+impl<I: CurveIterator> CurveIterator for TakeCapacityIterator<I> {
+    type CurveKind = I::CurveKind;
+
+    fn next_window(&mut self) -> Option<Window<<Self::CurveKind as CurveType>::WindowKind>> {
+        if self.remaining == TimeUnit::ZERO {
+            return None;
+        }
+
+        let window = self.iter.next_window()?;
+
+        match window.length() {
+            WindowEnd::Finite(length) if length <= self.remaining => {
+                self.remaining = self.remaining - length;
+                Some(window)
+            }
+            _ => {
+                let (head, _) = window.split_at(window.start + self.remaining);
+                self.remaining = TimeUnit::ZERO;
+                Some(head)
+            }
+        }
+    }
+}
+
+/// `CurveIterator` wrapper ending the curve at a horizon
+///
+/// See [`CurveIterator::clamp_to`]
+#[derive(Debug, Clone)]
+pub struct ClampIterator<I> {
+    /// the wrapped `CurveIterator`
+    iter: I,
+    /// the time at which the curve ends
+    horizon: TimeUnit,
+    /// whether the horizon has been reached
+    done: bool,
+}
+
+impl<I: CurveIterator> CurveIterator for ClampIterator<I> {
+    type CurveKind = I::CurveKind;
+
+    fn next_window(&mut self) -> Option<Window<<Self::CurveKind as CurveType>::WindowKind>> {
+        if self.done {
+            return None;
+        }
+
+        match self.iter.next_window() {
+            Some(window) if window.start < self.horizon => {
+                let (head, tail) = window.split_at(self.horizon);
+                self.done = !tail.is_empty();
+                Some(head)
+            }
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
 /**
 A `CurveIterator` that wraps either the `L` or `R` `CurveIterator`
 */
@@ -152,6 +903,32 @@ impl<L, R> EitherCurveIterator<L, R> {
     pub const fn right(right: R) -> Self {
         Self::Right(right)
     }
+
+    /**
+    Apply `left` or `right` depending on the Variant, unifying the result type
+    */
+    pub fn map_either<T, FL, FR>(self, left: FL, right: FR) -> T
+    where
+        FL: FnOnce(L) -> T,
+        FR: FnOnce(R) -> T,
+    {
+        match self {
+            EitherCurveIterator::Left(inner) => left(inner),
+            EitherCurveIterator::Right(inner) => right(inner),
+        }
+    }
+}
+
+/**
+Convert `Ok` into the Left Variant and `Err` into the Right Variant
+*/
+impl<L, R> From<Result<L, R>> for EitherCurveIterator<L, R> {
+    fn from(result: Result<L, R>) -> Self {
+        match result {
+            Ok(left) => Self::Left(left),
+            Err(right) => Self::Right(right),
+        }
+    }
 }
 
 impl<L, R, K> CurveIterator for EitherCurveIterator<L, R>
@@ -170,6 +947,35 @@ where
     }
 }
 
+/**
+A `CurveIterator` wrapping a boxed `CurveIterator` trait object
+
+Allows handling `CurveIterator`s of different types but the same `CurveKind` uniformly,
+e.g. collecting them into a `Vec` to aggregate them,
+without nesting [`EitherCurveIterator`]s.
+
+This comes at the cost of an allocation and dynamic dispatch for every window,
+so prefer `EitherCurveIterator` or generics where practical
+*/
+#[derive(Debug)]
+pub struct BoxedCurveIterator<'a, C>(Box<dyn CurveIterator<CurveKind = C> + 'a>);
+
+impl<'a, C: CurveType> BoxedCurveIterator<'a, C> {
+    /// Box the `CurveIterator` `iter`
+    #[must_use]
+    pub fn new<CI: CurveIterator<CurveKind = C> + 'a>(iter: CI) -> Self {
+        BoxedCurveIterator(Box::new(iter))
+    }
+}
+
+impl<C: CurveType> CurveIterator for BoxedCurveIterator<'_, C> {
+    type CurveKind = C;
+
+    fn next_window(&mut self) -> Option<Window<<Self::CurveKind as CurveType>::WindowKind>> {
+        self.0.next_window()
+    }
+}
+
 /// Wrap a `CurveIterator` to be a `CurveIterator` and an `Iterator`
 #[derive(Debug, Clone)]
 pub struct CurveIteratorIterator<I> {
@@ -188,7 +994,7 @@ where
     }
 }
 
-impl<CI: CurveIterator> CurveIterator for &mut CI {
+impl<CI: CurveIterator + ?Sized> CurveIterator for &mut CI {
     type CurveKind = CI::CurveKind;
 
     fn next_window(&mut self) -> Option<Window<<Self::CurveKind as CurveType>::WindowKind>> {
@@ -196,7 +1002,7 @@ impl<CI: CurveIterator> CurveIterator for &mut CI {
     }
 }
 
-impl<CI: CurveIterator> CurveIterator for Box<CI> {
+impl<CI: CurveIterator + ?Sized> CurveIterator for Box<CI> {
     type CurveKind = CI::CurveKind;
 
     fn next_window(&mut self) -> Option<Window<<Self::CurveKind as CurveType>::WindowKind>> {
@@ -0,0 +1,103 @@
+//! Module for the `CachedCurveIterator` implementation and definition
+
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt::Debug;
+
+use crate::curve::curve_types::CurveType;
+use crate::iterators::CurveIterator;
+use crate::window::Window;
+
+/// The state shared between all clones of a `CachedCurveIterator`
+#[derive(Debug)]
+struct Cache<I: CurveIterator> {
+    /// the wrapped `CurveIterator`, producing windows not yet cached
+    source: I,
+    /// all windows produced by `source` so far
+    windows: Vec<Window<<I::CurveKind as CurveType>::WindowKind>>,
+}
+
+/**
+`CurveIterator` wrapper memoizing the windows produced by the wrapped `CurveIterator`
+
+All clones share the cache, so a window is only calculated once,
+cloning continues at the same position replaying cached windows rather than recomputing them,
+use [`CachedCurveIterator::restart`] to replay from the first window.
+
+Useful for expensive `CurveIterator`s that feed multiple downstream calculations,
+e.g. the constrained demand of a server, see [`ConstrainedDemand`](crate::server::ConstrainedDemand)
+
+# Panics
+The cache is borrowed while the wrapped `CurveIterator` calculates the next window,
+requesting a window of a clone sharing the cache from within that calculation
+panics instead of observing the cache in an inconsistent state.
+As the wrapped `CurveIterator` is moved into the cache on creation
+this can only happen when it is given access to a clone through shared state
+*/
+#[derive(Debug)]
+pub struct CachedCurveIterator<I: CurveIterator> {
+    /// the cache shared between all clones
+    cache: Rc<RefCell<Cache<I>>>,
+    /// the index of the next window to return
+    position: usize,
+}
+
+impl<I: CurveIterator> Clone for CachedCurveIterator<I> {
+    fn clone(&self) -> Self {
+        CachedCurveIterator {
+            cache: Rc::clone(&self.cache),
+            position: self.position,
+        }
+    }
+}
+
+impl<I: CurveIterator> CachedCurveIterator<I> {
+    /// Wrap a `CurveIterator` memoizing its windows
+    #[must_use]
+    pub fn new(source: I) -> Self {
+        CachedCurveIterator {
+            cache: Rc::new(RefCell::new(Cache {
+                source,
+                windows: Vec::new(),
+            })),
+            position: 0,
+        }
+    }
+
+    /// Create a `CurveIterator` sharing the cache, but starting again from the first window
+    #[must_use]
+    pub fn restart(&self) -> Self {
+        CachedCurveIterator {
+            cache: Rc::clone(&self.cache),
+            position: 0,
+        }
+    }
+}
+
+impl<I: CurveIterator> CurveIterator for CachedCurveIterator<I>
+where
+    Self: Debug,
+{
+    type CurveKind = I::CurveKind;
+
+    fn next_window(&mut self) -> Option<Window<<Self::CurveKind as CurveType>::WindowKind>> {
+        let mut cache = self.cache.try_borrow_mut().unwrap_or_else(|_| {
+            panic!(
+                "CachedCurveIterator re-entered while calculating window {}",
+                self.position
+            )
+        });
+
+        let window = if let Some(window) = cache.windows.get(self.position) {
+            window.clone()
+        } else {
+            let window = cache.source.next_window()?;
+            cache.windows.push(window.clone());
+            window
+        };
+
+        self.position += 1;
+        Some(window)
+    }
+}
@@ -5,7 +5,7 @@
 //! also `FromIterator` implementation for `Curve`
 //!
 
-use core::fmt::Debug;
+use core::fmt::{Debug, Display, Formatter};
 use core::iter::{FromIterator, FusedIterator};
 use core::marker::PhantomData;
 
@@ -15,7 +15,7 @@ pub use aggregate::AggregationIterator;
 pub use delta::{
     CurveDeltaIterator,
     Delta::{self, *},
-    InverseCurveIterator, OverlapIterator, RemainingSupplyIterator,
+    InverseCurveIterator, OverlapIterator, RemainingDemandIterator, RemainingSupplyIterator,
 };
 pub use split::CurveSplitIterator;
 
@@ -25,8 +25,8 @@ use crate::iterators::join::JoinAdjacentIterator;
 use crate::iterators::CurveIterator;
 use crate::time::{TimeUnit, UnitNumber};
 use crate::window::window_types::WindowType;
-use crate::window::Window;
 use crate::window::WindowEnd;
+use crate::window::{BudgetGroups, Window};
 
 mod aggregate;
 mod delta;
@@ -101,6 +101,55 @@ impl<C: CurveType> Iterator for CurveIter<C> {
     }
 }
 
+/// `CurveIterator` for iterating a borrowed [`Curve`],
+/// cloning the windows as they are returned
+///
+/// See [`Curve::iter_windows`]
+#[derive(Debug)]
+pub struct CurveWindowsIter<'a, C: CurveType> {
+    /// The remaining windows of the Curve
+    windows: core::slice::Iter<'a, Window<C::WindowKind>>,
+}
+
+impl<C: CurveType> Clone for CurveWindowsIter<'_, C> {
+    fn clone(&self) -> Self {
+        CurveWindowsIter {
+            windows: self.windows.clone(),
+        }
+    }
+}
+
+impl<C: CurveType> Curve<C> {
+    /// Iterate the windows of the Curve without consuming it
+    ///
+    /// Unlike [`Curve::as_windows`] the returned iterator is a `CurveIterator`
+    /// so that a borrowed Curve can be used with other `CurveIterator`s
+    #[must_use]
+    pub fn iter_windows(&self) -> CurveWindowsIter<'_, C> {
+        CurveWindowsIter {
+            windows: self.as_windows().iter(),
+        }
+    }
+}
+
+impl<C: CurveType> CurveIterator for CurveWindowsIter<'_, C> {
+    type CurveKind = C;
+
+    fn next_window(&mut self) -> Option<Window<C::WindowKind>> {
+        self.windows.next().cloned()
+    }
+}
+
+impl<C: CurveType> FusedIterator for CurveWindowsIter<'_, C> {}
+
+impl<C: CurveType> Iterator for CurveWindowsIter<'_, C> {
+    type Item = Window<C::WindowKind>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_window()
+    }
+}
+
 /// Wrapper for wrapping an Iterator into a `CurveIterator`
 #[derive(Debug)]
 pub struct IterCurveWrapper<I, C> {
@@ -170,13 +219,22 @@ where
     /// The returned Iterator panics when not enough capacity was available in a processed group.
     /// The panics occurs when the first window of the next group is requested
     pub fn new(to_be_checked: I, capacity: TimeUnit, interval: TimeUnit) -> Self {
-        let inner = InnerCapacityCheckIterator {
-            iter: CurveSplitIterator::new(to_be_checked, interval),
-            capacity,
-            interval,
-            current_group: 0,
-            accounted: WindowEnd::Finite(TimeUnit::ZERO),
-        };
+        Self::new_with_phase(to_be_checked, capacity, interval, TimeUnit::ZERO)
+    }
+
+    /// Create a new `CapacityCheckIterator` with group boundaries at `phase + k * interval`
+    ///
+    /// The shortened first group before the first boundary has no budget
+    /// and is therefore not required to contain any capacity,
+    /// see [`ServerProperties::group_capacity`](crate::server::ServerProperties::group_capacity)
+    /// and [`CapacityCheckIterator::new`]
+    pub fn new_with_phase(
+        to_be_checked: I,
+        capacity: TimeUnit,
+        interval: TimeUnit,
+        phase: TimeUnit,
+    ) -> Self {
+        let inner = InnerCapacityCheckIterator::new(to_be_checked, capacity, interval, phase);
 
         let outer = unsafe { JoinAdjacentIterator::new(inner) };
 
@@ -197,15 +255,15 @@ where
     }
 }
 
-/// Inner Iterator for the `CapacityCheckIterator`
+/// Inner Iterator for the `CapacityCheckIterator` and `TryCapacityCheckIterator`
 #[derive(Debug, Clone)]
 struct InnerCapacityCheckIterator<W, I> {
     /// wrapped curve split iterator
     iter: CurveSplitIterator<W, I>,
     /// the capacity each interval should have at least
     capacity: TimeUnit,
-    /// the interval in which to check for sufficient capacity
-    interval: TimeUnit,
+    /// the groups in which to check for sufficient capacity
+    groups: BudgetGroups,
     /// the current group being accounted
     current_group: UnitNumber,
     /// the capacity currently witnessed up to now in the current group
@@ -221,32 +279,204 @@ where
     type Item = Window<W>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        match self.try_next() {
+            Ok(next) => next,
+            Err(error) => {
+                #[cfg(feature = "log")]
+                log::error!("Capacity check failed: {error}");
+                panic!("{}", error)
+            }
+        }
+    }
+}
+
+impl<W, I> InnerCapacityCheckIterator<W, I>
+where
+    W: WindowType,
+    I: CurveIterator,
+    I::CurveKind: CurveType<WindowKind = W>,
+{
+    /// Create a new `InnerCapacityCheckIterator` with group boundaries at `phase + k * interval`
+    fn new(to_be_checked: I, capacity: TimeUnit, interval: TimeUnit, phase: TimeUnit) -> Self {
+        InnerCapacityCheckIterator {
+            iter: CurveSplitIterator::new_with_offset(to_be_checked, interval, phase),
+            capacity,
+            groups: BudgetGroups::new(interval, phase),
+            current_group: 0,
+            accounted: WindowEnd::Finite(TimeUnit::ZERO),
+        }
+    }
+
+    /// The capacity required in `group`,
+    /// none in the shortened first group as it has no budget
+    fn required_capacity(&self, group: UnitNumber) -> TimeUnit {
+        if self.groups.is_shortened(group) {
+            TimeUnit::ZERO
+        } else {
+            self.capacity
+        }
+    }
+
+    /// Return the next window, or an error when a group turned out to lack capacity
+    ///
+    /// A group is only checked once the first window of a later group is processed
+    ///
+    /// # Errors
+    /// When a processed group contained less capacity than required
+    ///
+    /// # Panics
+    /// When the capacity accounted for a group overflows
+    fn try_next(&mut self) -> Result<Option<Window<W>>, CapacityError> {
         if let Some(next) = self.iter.next() {
-            let next_group = next.budget_group(self.interval);
+            let next_group = self.groups.group(next.start);
 
             if next_group == self.current_group {
-                self.accounted += next.length();
-            } else if next_group == self.current_group + 1 {
-                if self.accounted < self.capacity {
-                    panic!(
-                        "Not enough capacity in group {}, expected at least {:?} capacity , got {:?}, next group {:?}!",
-                        self.current_group, self.capacity, self.accounted, next_group
-                    );
+                // guard against wrapping around to a nonsensical capacity
+                self.accounted = self
+                    .accounted
+                    .checked_add(next.length())
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Capacity overflow while accounting group {}",
+                            self.current_group
+                        )
+                    });
+            } else {
+                #[cfg(feature = "log")]
+                log::debug!(
+                    "Capacity check accounted {} of at least {} capacity in group {}",
+                    self.accounted,
+                    self.required_capacity(self.current_group),
+                    self.current_group
+                );
+
+                if let WindowEnd::Finite(observed) = self.accounted {
+                    let expected = self.required_capacity(self.current_group);
+                    if observed < expected {
+                        return Err(CapacityError {
+                            group: self.current_group,
+                            expected,
+                            observed,
+                        });
+                    }
+                }
+
+                if next_group != self.current_group + 1 && self.capacity > TimeUnit::ZERO {
+                    // the groups in between have no capacity at all
+                    return Err(CapacityError {
+                        group: self.current_group + 1,
+                        expected: self.capacity,
+                        observed: TimeUnit::ZERO,
+                    });
                 }
+
                 self.current_group = next_group;
                 self.accounted = next.length();
-            } else {
-                panic!(
-                    "No capacity for group {}, expected {:?} capacity, next group {}!",
-                    self.current_group + 1,
-                    self.capacity,
-                    next_group
-                );
-            };
+            }
 
-            Some(next)
+            Ok(Some(next))
         } else {
-            None
+            Ok(None)
+        }
+    }
+}
+
+/// Error for a group lacking the capacity checked for
+/// by a [`TryCapacityCheckIterator`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CapacityError {
+    /// the group that lacked capacity
+    pub group: UnitNumber,
+    /// the capacity the group should have had at least
+    pub expected: TimeUnit,
+    /// the capacity that was available in the group
+    pub observed: TimeUnit,
+}
+
+impl Display for CapacityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Not enough capacity in group {}, expected at least {} capacity, got {}!",
+            self.group, self.expected, self.observed
+        )
+    }
+}
+
+/// Checks that each interval contains a minimum amount of capacity,
+/// the fallible counterpart of [`CapacityCheckIterator`]
+///
+/// Not a `CurveIterator` as it reports a lack of capacity as an error
+/// instead of panicking
+#[derive(Debug, Clone)]
+pub struct TryCapacityCheckIterator<W, I> {
+    /// the inner iterator doing all the work
+    iter: InnerCapacityCheckIterator<W, I>,
+    /// the window to be joined with adjacent windows before being returned
+    pending: Option<Window<W>>,
+    /// the error encountered, once encountered it is returned on every call
+    error: Option<CapacityError>,
+}
+
+impl<W, I> TryCapacityCheckIterator<W, I>
+where
+    W: WindowType,
+    I: CurveIterator,
+    I::CurveKind: CurveType<WindowKind = W>,
+{
+    /// Create a new `TryCapacityCheckIterator`
+    ///
+    /// That checks that ever `interval` of the curve `to_be_checked` contains at least
+    /// `capacity` of capacity
+    pub fn new(to_be_checked: I, capacity: TimeUnit, interval: TimeUnit) -> Self {
+        Self::new_with_phase(to_be_checked, capacity, interval, TimeUnit::ZERO)
+    }
+
+    /// Create a new `TryCapacityCheckIterator` with group boundaries at `phase + k * interval`,
+    /// see [`CapacityCheckIterator::new_with_phase`]
+    pub fn new_with_phase(
+        to_be_checked: I,
+        capacity: TimeUnit,
+        interval: TimeUnit,
+        phase: TimeUnit,
+    ) -> Self {
+        TryCapacityCheckIterator {
+            iter: InnerCapacityCheckIterator::new(to_be_checked, capacity, interval, phase),
+            pending: None,
+            error: None,
+        }
+    }
+
+    /// Return the next window of the checked curve
+    ///
+    /// # Errors
+    /// When not enough capacity was available in a processed group,
+    /// the error occurs when the first window of the next group is requested
+    /// and is repeated on every later call
+    pub fn next_window(&mut self) -> Result<Option<Window<W>>, CapacityError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        loop {
+            match self.iter.try_next() {
+                Err(error) => {
+                    self.error = Some(error);
+                    return Err(error);
+                }
+                Ok(None) => return Ok(self.pending.take()),
+                Ok(Some(next)) => match self.pending.take() {
+                    Some(pending) if pending.overlaps(&next) => {
+                        self.pending = Some(Window::new(pending.start, next.end));
+                    }
+                    Some(pending) => {
+                        self.pending = Some(next);
+                        return Ok(Some(pending));
+                    }
+                    None => self.pending = Some(next),
+                },
+            }
         }
     }
 }
@@ -6,7 +6,9 @@ use core::iter::Fuse;
 use crate::curve::curve_types::CurveType;
 use crate::curve::Aggregate;
 use crate::iterators::peek::Peeker;
-use crate::iterators::{CurveIterator, CurveIteratorIterator, ReclassifyIterator};
+use crate::iterators::{
+    CurveIterator, CurveIteratorIterator, ReclassifyIterator, ReinterpretIterator,
+};
 use crate::server::{
     ActualServerExecution, AggregatedServerDemand, ConstrainedServerDemand,
     HigherPriorityServerDemand, HigherPriorityServerExecution,
@@ -21,7 +23,7 @@ use core::fmt::Debug;
 ///
 #[derive(Debug, Clone)]
 pub struct AggregationIterator<I, W> {
-    /// The CurveIterators to aggregate
+    /// The `CurveIterator`s to aggregate
     curves: Vec<Peeker<Fuse<CurveIteratorIterator<I>>, Window<W>>>,
 }
 
@@ -31,6 +33,8 @@ where
     I::CurveKind: CurveType<WindowKind = W>,
 {
     /// Create a new `AggregationIterator`
+    ///
+    /// All `curves` share the type `I` and therefore the window kind `W`
     #[must_use]
     pub fn new(curves: Vec<I>) -> Self {
         AggregationIterator {
@@ -42,6 +46,25 @@ where
     }
 }
 
+impl<I, W> AggregationIterator<I, W>
+where
+    I: CurveIterator,
+    I::CurveKind: CurveType<WindowKind = W>,
+    W: Debug,
+{
+    /// Classify the aggregation as the `CurveType` `O`,
+    /// restricted to the conversions allowed by [`AggregateInto`]
+    /// e.g. from [`TaskDemand`] to [`AggregatedServerDemand`]
+    #[must_use]
+    pub const fn aggregate_into<O>(self) -> ReinterpretIterator<Self, O>
+    where
+        I::CurveKind: AggregateInto<O>,
+        O: CurveType,
+    {
+        ReinterpretIterator::new(self)
+    }
+}
+
 impl<I, W> CurveIterator for AggregationIterator<I, W>
 where
     I: CurveIterator,
@@ -136,3 +159,18 @@ where
         AggregationIterator::new(iter.collect()).reclassify()
     }
 }
+
+impl<AI, O, W> Aggregate<AI> for ReinterpretIterator<AggregationIterator<AI, W>, O>
+where
+    <AI as CurveIterator>::CurveKind: AggregateInto<O>,
+    AI: CurveIterator,
+    AI::CurveKind: CurveType<WindowKind = W>,
+    W: Debug,
+{
+    fn aggregate<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = AI>,
+    {
+        ReinterpretIterator::new(AggregationIterator::new(iter.collect()))
+    }
+}
@@ -1,5 +1,6 @@
 //! Module for the implementation of the Curve delta operation using iterators
 
+use core::cmp::Ordering;
 use core::fmt::Debug;
 use core::iter::{FilterMap, FusedIterator};
 use core::marker::PhantomData;
@@ -51,9 +52,9 @@ impl<D, S, DI, SI> Delta<D, S, DI, SI> {
 /// See [`CurveDeltaIterator::remaining_supply`]
 #[derive(Debug)]
 pub struct RemainingSupplyIterator<S, D, SI, DI> {
-    /// The CurveDeltaIterator from which to collect the supply
+    /// The `CurveDeltaIterator` from which to collect the supply
     delta: Option<CurveDeltaIterator<D, S, DI, SI>>,
-    /// The remaining end_supply to return
+    /// The remaining `end_supply` to return
     end_supply: Option<Box<SI>>,
 }
 
@@ -89,16 +90,78 @@ where
                     self.end_supply = None;
                 }
             }
+            if let Some(delta_iter) = self.delta.as_mut() {
+                loop {
+                    if let Some(delta) = delta_iter.next() {
+                        if let Delta::RemainingSupply(supply) = delta {
+                            return Some(supply);
+                        } else if let Delta::EndSupply(supply) = delta {
+                            self.end_supply = Some(supply);
+                            continue 'outer;
+                        }
+                    } else {
+                        self.delta = None;
+                        break;
+                    }
+                }
+            }
+            return None;
+        }
+    }
+}
+
+/// Iterator Adapter for filtering a `CurveDeltaIterator` into only the remaining demand
+///
+/// See [`CurveDeltaIterator::remaining_demand`]
+#[derive(Debug)]
+pub struct RemainingDemandIterator<S, D, SI, DI> {
+    /// The `CurveDeltaIterator` from which to collect the demand
+    delta: Option<CurveDeltaIterator<D, S, DI, SI>>,
+    /// The remaining `end_demand` to return
+    end_demand: Option<Peeker<CurveIteratorIterator<Box<DI>>, Window<D>>>,
+}
+
+impl<S, D, SI: Clone, DI: Clone> Clone for RemainingDemandIterator<S, D, SI, DI> {
+    fn clone(&self) -> Self {
+        RemainingDemandIterator {
+            delta: self.delta.clone(),
+            end_demand: self.end_demand.clone(),
+        }
+    }
+}
+
+impl<SI, DI> CurveIterator
+    for RemainingDemandIterator<
+        <SI::CurveKind as CurveType>::WindowKind,
+        <DI::CurveKind as CurveType>::WindowKind,
+        SI,
+        DI,
+    >
+where
+    Self: Debug,
+    SI: CurveIterator,
+    DI: CurveIterator,
+{
+    type CurveKind = DI::CurveKind;
+
+    fn next_window(&mut self) -> Option<Window<<DI::CurveKind as CurveType>::WindowKind>> {
+        'outer: loop {
+            if let Some(end_demand) = self.end_demand.as_mut() {
+                if let Some(demand) = end_demand.next() {
+                    return Some(demand);
+                } else {
+                    self.end_demand = None;
+                }
+            }
             if let Some(delta_iter) = self.delta.as_mut() {
                 loop {
                     if let Some(delta) = delta_iter.next() {
                         match delta {
-                            Delta::Overlap(_) | Delta::EndDemand(_) | Delta::RemainingDemand(_) => {
-                                continue
+                            Delta::Overlap(_) | Delta::EndSupply(_) | Delta::RemainingSupply(_) => {
                             }
-                            Delta::RemainingSupply(supply) => return Some(supply),
-                            Delta::EndSupply(supply) => {
-                                self.end_supply = Some(supply);
+                            Delta::RemainingDemand(demand) => return Some(demand),
+                            Delta::EndDemand(demand) => {
+                                self.end_demand = Some(demand);
                                 continue 'outer;
                             }
                         }
@@ -153,18 +216,22 @@ impl<I: CurveIterator, C: CurveType> CurveIterator for InverseCurveIterator<I, C
     fn next_window(&mut self) -> Option<Window<C::WindowKind>> {
         if let WindowEnd::Finite(mut previous_end) = self.previous_end {
             while let Some(window) = self.iter.next_window() {
-                if self.previous_end < window.start {
-                    let result = Window::new(previous_end, window.start);
-                    self.previous_end = window.end;
-                    return Some(result);
-                } else if self.previous_end == window.start {
-                    self.previous_end = window.end;
-                    match self.previous_end {
-                        WindowEnd::Finite(end) => previous_end = end,
-                        WindowEnd::Infinite => return None,
+                match previous_end.cmp(&window.start) {
+                    Ordering::Less => {
+                        let result = Window::new(previous_end, window.start);
+                        self.previous_end = window.end;
+                        return Some(result);
+                    }
+                    Ordering::Equal => {
+                        self.previous_end = window.end;
+                        match self.previous_end {
+                            WindowEnd::Finite(end) => previous_end = end,
+                            WindowEnd::Infinite => return None,
+                        }
+                    }
+                    Ordering::Greater => {
+                        panic!("Overlapping Windows in CurveIterator 'self.iter'")
                     }
-                } else {
-                    panic!("Overlapping Windows in CurveIterator 'self.iter'")
                 }
             }
 
@@ -212,6 +279,15 @@ impl<S, D, SI, DI> CurveDeltaIterator<D, S, DI, SI> {
         }
     }
 
+    /// Turn the `CurveDeltaIterator` into a `CurveIterator` that returns only the Remaining Demand Windows
+    #[must_use]
+    pub const fn remaining_demand(self) -> RemainingDemandIterator<S, D, SI, DI> {
+        RemainingDemandIterator {
+            delta: Some(self),
+            end_demand: None,
+        }
+    }
+
     /// Turn the `CurveDeltaIterator` into a `CurveIterator` that returns only the Overlap Windows
     #[must_use]
     pub fn overlap<C>(self) -> OverlapIterator<DI, SI, D, S, C>
@@ -7,12 +7,13 @@ use crate::curve::curve_types::CurveType;
 use crate::iterators::CurveIterator;
 use crate::time::TimeUnit;
 use crate::window::window_types::WindowType;
-use crate::window::Window;
 use crate::window::WindowEnd;
+use crate::window::{BudgetGroups, Window};
 
 /// Curve Iterator for splitting a Curve in fixed Intervals
 ///
-/// Split the curve on every interval boundary as defined in Definition 8. of the paper
+/// Split the curve on every interval boundary as defined in Definition 8. of the paper,
+/// the boundaries being offset by an optional phase
 /// When the last window of the input `CurveIterator` is an infinite window
 /// that window will be spilt at most once, and in that case the last window returned
 /// will start on a group boundary and be infinite
@@ -27,8 +28,8 @@ pub struct CurveSplitIterator<W, CI> {
     iter: Box<CI>,
     /// The remaining tail from the head of the last split
     tail: Option<Window<W>>,
-    /// The interval at which to perform the splits
-    interval: TimeUnit,
+    /// The groups between the split boundaries
+    groups: BudgetGroups,
 }
 
 impl<W: WindowType, CI> CurveSplitIterator<W, CI>
@@ -37,10 +38,21 @@ where
 {
     /// Split the `CurveIterator` at every interval
     pub fn new(iter: CI, interval: TimeUnit) -> Self {
+        Self::new_with_offset(iter, interval, TimeUnit::ZERO)
+    }
+
+    /// Split the `CurveIterator` at `phase + k * interval` for every k
+    ///
+    /// The phase is taken modulo the interval,
+    /// so the windows before the first boundary form a shortened first group
+    ///
+    /// # Panics
+    /// When the interval is zero
+    pub fn new_with_offset(iter: CI, interval: TimeUnit, phase: TimeUnit) -> Self {
         CurveSplitIterator {
             iter: Box::new(iter),
             tail: None,
-            interval,
+            groups: BudgetGroups::new(interval, phase),
         }
     }
 }
@@ -63,17 +75,19 @@ where
         let first = self.tail.take().or_else(|| self.iter.next_window());
 
         first.map(|first| {
-            let k = first.start / self.interval;
-            if first.end <= (k + 1) * self.interval
-                || first.start == k * self.interval && first.end == WindowEnd::Infinite
-            {
+            let group = self.groups.group(first.start);
+            let group_end = self.groups.end(group);
+            let on_group_start =
+                !self.groups.is_shortened(group) && first.start == self.groups.start(group);
+
+            if first.end <= group_end || on_group_start && first.end == WindowEnd::Infinite {
                 // window belongs completely to a group
                 // or window starts on a group boundary and is infinite return as is
                 first
             } else {
                 // window belongs only partially to this group
-                let init = Window::new(first.start, (k + 1) * self.interval);
-                let tail = Window::new((k + 1) * self.interval, first.end);
+                let init = Window::new(first.start, group_end);
+                let tail = Window::new(group_end, first.end);
 
                 // remember remaining tail for next group
                 self.tail = Some(tail);
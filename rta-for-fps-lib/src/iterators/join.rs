@@ -4,9 +4,11 @@ use core::fmt::Debug;
 use core::iter::Fuse;
 use core::marker::PhantomData;
 
-use crate::curve::curve_types::CurveType;
+use crate::curve::curve_types::{CurveType, UnspecifiedCurve};
+use crate::iterators::curve::IterCurveWrapper;
 use crate::iterators::peek::Peeker;
-use crate::iterators::{CurveIterator, CurveIteratorIterator};
+use crate::iterators::{CurveIterator, CurveIteratorIterator, DebugCheckIterator};
+use crate::window::window_types::WindowType;
 use crate::window::Window;
 
 /// `CurveIterator` for turning an Iterator that returns ordered windows,
@@ -48,6 +50,34 @@ impl<I, W, C> JoinAdjacentIterator<I, W, C> {
     }
 }
 
+impl<I, W, C>
+    JoinAdjacentIterator<
+        CurveIteratorIterator<DebugCheckIterator<IterCurveWrapper<I, UnspecifiedCurve<W>>>>,
+        W,
+        C,
+    >
+where
+    I: Iterator<Item = Window<W>> + Debug,
+    W: WindowType,
+{
+    /// Create a new `JoinAdjacentIterator` like [`JoinAdjacentIterator::new`],
+    /// but in debug builds check the invariants of the Iterator I
+    /// using [`CurveIterator::debug_check_invariants`]
+    ///
+    /// In release builds this behaves identically to [`JoinAdjacentIterator::new`]
+    ///
+    /// # Safety
+    ///
+    /// The same as for [`JoinAdjacentIterator::new`] as the invariants are only checked in debug builds
+    pub unsafe fn new_checked(iter: I) -> Self {
+        let checked = IterCurveWrapper::<_, UnspecifiedCurve<W>>::new(iter)
+            .debug_check_invariants()
+            .into_iterator();
+
+        JoinAdjacentIterator::new(checked)
+    }
+}
+
 impl<C: CurveIterator>
     JoinAdjacentIterator<
         CurveIteratorIterator<C>,
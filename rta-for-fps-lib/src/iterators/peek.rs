@@ -19,7 +19,7 @@ pub struct PeekRef<'a, I> {
     mut_ref: PhantomData<&'a mut I>,
 }
 
-impl<'a, I> Deref for PeekRef<'a, I> {
+impl<I> Deref for PeekRef<'_, I> {
     type Target = I;
 
     fn deref(&self) -> &Self::Target {
@@ -27,7 +27,7 @@ impl<'a, I> Deref for PeekRef<'a, I> {
     }
 }
 
-impl<'a, I> DerefMut for PeekRef<'a, I> {
+impl<I> DerefMut for PeekRef<'_, I> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.as_mut()
     }
@@ -49,7 +49,11 @@ impl<'a, I> PeekRef<'a, I> {
     }
 
     /// Consume the `PeekRef` and get the peek element
+    ///
+    /// # Panics
+    /// Never, as a `PeekRef` is only constructed for a filled peek slot
     #[allow(clippy::must_use_candidate)]
+    #[allow(clippy::missing_const_for_fn)] // false positive, Option::flatten is not const
     pub fn take(mut self) -> I {
         unsafe {
             // Safety:
@@ -102,7 +106,7 @@ pub struct Peeker<I, IT> {
     /// If Some contains the peeked at value
     /// which may be a None
     /// Otherwise we have not peeked since the last next call
-    /// or the value was taken via a PeekRef
+    /// or the value was taken via a `PeekRef`
     #[allow(clippy::option_option)]
     peek_window: Option<Option<IT>>,
 }
@@ -112,7 +116,7 @@ where
     I: Iterator<Item = IT>,
 {
     /// Create a new `Peeker`
-    pub fn new(inner: I) -> Self {
+    pub const fn new(inner: I) -> Self {
         Self {
             iter: inner,
             peek_window: None,
@@ -149,7 +153,7 @@ where
     /// If there is a window held as peek
     pub fn restore_peek(&mut self, window: IT) {
         if self.peek_window.take().flatten().is_none() {
-            self.peek_window = Some(Some(window))
+            self.peek_window = Some(Some(window));
         } else {
             panic!("Restoring over existing peek window!")
         }
@@ -10,7 +10,9 @@ use crate::curve::Curve;
 use crate::iterators::curve::CurveSplitIterator;
 use crate::iterators::peek::Peeker;
 use crate::iterators::{CurveIterator, CurveIteratorIterator};
-use crate::server::{ActualServerExecution, ServerProperties, UnconstrainedServerExecution};
+use crate::server::{
+    ActualServerExecution, ServerKind, ServerProperties, UnconstrainedServerExecution,
+};
 use crate::time::{TimeUnit, UnitNumber};
 use crate::window::WindowEnd;
 use crate::window::{Demand, Window};
@@ -39,7 +41,7 @@ pub struct ActualServerExecutionIterator<AC, CDC> {
         Box<CurveSplitIterator<<UnconstrainedServerExecution as CurveType>::WindowKind, AC>>,
     /// the peek of the remaining available execution that is not yet consumed
     execution_peek: Vec<Window<<UnconstrainedServerExecution as CurveType>::WindowKind>>,
-    /// the group spend_budget is referring to
+    /// the group `spend_budget` is referring to
     current_group: UnitNumber,
     /// the spend budget of the current group
     // remembering one group is enough as we go through them in order
@@ -50,7 +52,7 @@ pub struct ActualServerExecutionIterator<AC, CDC> {
     constrained_peek: Vec<Window<Demand>>,
 }
 
-impl<'a, AC: Clone, CDC: Clone> Clone for ActualServerExecutionIterator<AC, CDC> {
+impl<AC: Clone, CDC: Clone> Clone for ActualServerExecutionIterator<AC, CDC> {
     fn clone(&self) -> Self {
         ActualServerExecutionIterator {
             server_properties: self.server_properties,
@@ -79,8 +81,11 @@ impl<AC, CDC> ActualServerExecutionIterator<AC, CDC> {
         CDC::CurveKind: CurveType<WindowKind = Demand>,
     {
         // Algorithm 4. (1)
-        let split_execution =
-            CurveSplitIterator::new(available_execution, server_properties.interval);
+        let split_execution = CurveSplitIterator::new_with_offset(
+            available_execution,
+            server_properties.interval,
+            server_properties.phase,
+        );
 
         ActualServerExecutionIterator {
             server_properties,
@@ -127,7 +132,10 @@ where
                     .or_else(|| self.available_execution.next());
 
                 if let Some(mut supply_window) = supply {
-                    let window_group = supply_window.budget_group(self.server_properties.interval);
+                    let window_group = self.server_properties.budget_group(supply_window.start);
+                    // a Background Server is not limited by a budget
+                    let unlimited =
+                        matches!(self.server_properties.server_type, ServerKind::Background);
 
                     // (a)
                     if supply_window.end <= demand_window.start {
@@ -139,13 +147,16 @@ where
                         // reset spend budget
                         self.spend_budget = TimeUnit::ZERO;
                         self.current_group = window_group;
-                    } else if self.spend_budget >= self.server_properties.capacity {
+                    } else if !unlimited
+                        && self.spend_budget
+                            >= self.server_properties.group_capacity(self.current_group)
+                    {
                         if supply_window.end == WindowEnd::Infinite {
                             // Infinite supply window advance to next group
                             self.spend_budget = TimeUnit::ZERO;
                             self.current_group += 1;
                             supply_window.start =
-                                self.current_group * self.server_properties.interval;
+                                self.server_properties.group_start(self.current_group);
                         } else {
                             // budget exhausted skip supply window
                             continue;
@@ -154,19 +165,19 @@ where
 
                     // (b)
 
-                    let remaining_budget = self.server_properties.capacity - self.spend_budget;
-
-                    let valid_demand_segment = if demand_window.length() > remaining_budget {
-                        let valid_end = demand_window.start + remaining_budget;
-                        let valid = Window::new(demand_window.start, valid_end);
-                        let residual = Window::new(valid_end, demand_window.end);
-
-                        self.constrained_peek.push(residual);
-                        valid
+                    let (valid_demand_segment, residual) = if unlimited {
+                        (demand_window, Window::empty())
                     } else {
-                        demand_window
+                        let remaining_budget =
+                            self.server_properties.group_capacity(self.current_group)
+                                - self.spend_budget;
+                        demand_window.split_at(demand_window.start + remaining_budget)
                     };
 
+                    if !residual.is_empty() {
+                        self.constrained_peek.push(residual);
+                    }
+
                     // (d)
                     let result = Window::delta(&supply_window, &valid_demand_segment);
 
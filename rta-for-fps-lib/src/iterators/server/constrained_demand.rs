@@ -39,7 +39,7 @@ pub struct ConstrainedServerDemandIterator<I> {
     remainder: Vec<Window<<ConstrainedServerDemand as CurveType>::WindowKind>>,
 }
 
-impl<'a, I> ConstrainedServerDemandIterator<I>
+impl<I> ConstrainedServerDemandIterator<I>
 where
     I: CurveIterator<CurveKind = AggregatedServerDemand>,
 {
@@ -47,7 +47,11 @@ where
     /// the main part for calculating the Constraint Server Demand Curve
     pub fn new(server_properties: ServerProperties, aggregated_demand: I) -> Self {
         // Algorithm 1. (1)
-        let split = CurveSplitIterator::new(aggregated_demand, server_properties.interval);
+        let split = CurveSplitIterator::new_with_offset(
+            aggregated_demand,
+            server_properties.interval,
+            server_properties.phase,
+        );
         ConstrainedServerDemandIterator {
             server_properties,
             demand: Peeker::new(Box::new(split)),
@@ -83,8 +87,8 @@ where
             match (next_group, spill) {
                 (None, None) => None,
                 (Some(group_head), Some(spill)) => {
-                    let k_group_head = group_head.start / self.server_properties.interval;
-                    let k_spill = spill.start / self.server_properties.interval;
+                    let k_group_head = self.server_properties.budget_group(group_head.start);
+                    let k_spill = self.server_properties.budget_group(spill.start);
 
                     match k_group_head.cmp(&k_spill) {
                         Ordering::Less => {
@@ -96,8 +100,7 @@ where
                             let mut windows = alloc::vec![group_head.take()];
 
                             for window in &mut self.demand {
-                                if window.budget_group(self.server_properties.interval)
-                                    == k_group_head
+                                if self.server_properties.budget_group(window.start) == k_group_head
                                 {
                                     windows.push(window);
                                 } else {
@@ -127,13 +130,13 @@ where
                     }
                 }
                 (Some(group_head), None) => {
-                    let k_group_head = group_head.start / self.server_properties.interval;
+                    let k_group_head = self.server_properties.budget_group(group_head.start);
                     // no spill, only next group
 
                     let mut windows = alloc::vec![group_head.take()];
 
                     for window in &mut self.demand {
-                        if window.budget_group(self.server_properties.interval) == k_group_head {
+                        if self.server_properties.budget_group(window.start) == k_group_head {
                             windows.push(window);
                         } else {
                             self.demand.restore_peek(window);
@@ -152,7 +155,7 @@ where
                 (None, Some(spill)) => {
                     // only spill remaining
 
-                    let k = spill.start / self.server_properties.interval;
+                    let k = self.server_properties.budget_group(spill.start);
 
                     let curve = Curve::new(spill);
 
@@ -167,7 +170,7 @@ impl<I> ConstrainedServerDemandIterator<I>
 where
     I: CurveIterator<CurveKind = AggregatedServerDemand>,
 {
-    /// Process the group with index `k_group_head` and `demand `curve`
+    /// Process the group with index `k_group_head` and `demand` `curve`
     fn process_group(
         &mut self,
         k_group_head: usize,
@@ -195,12 +198,12 @@ where
                 .sum::<WindowEnd>();
 
         if delta_k > TimeUnit::ZERO {
-            let spill_start = (k_group_head + 1) * self.server_properties.interval;
+            let spill_start = self.server_properties.group_end(k_group_head);
             self.spill = Some(Window::new(spill_start, spill_start + delta_k));
         }
 
-        let result = self.remainder.pop();
-        assert!(result.is_some());
-        result
+        // a group without budget, like the shortened first group of a phased server,
+        // spills all of its demand so continue with the next group
+        self.remainder.pop().or_else(|| self.next_window())
     }
 }
@@ -5,7 +5,7 @@ use crate::iterators::CurveIterator;
 use crate::task::curve_types::TaskDemand;
 use crate::task::Task;
 use crate::time::{TimeUnit, UnitNumber};
-use crate::window::{Demand, Window};
+use crate::window::{Demand, Window, WindowEnd};
 
 /// `CurveIterator` for a Tasks Demand
 #[derive(Debug, Clone)]
@@ -14,13 +14,36 @@ pub struct TaskDemandIterator {
     task: Task,
     /// The next Job index for which to generate Demand
     next_job: UnitNumber,
+    /// Stop before the first window ending after this limit
+    limit: WindowEnd,
+    /// The end of the previous window, with jitter a window can't start before it
+    previous_end: TimeUnit,
 }
 
 impl TaskDemandIterator {
     /// Create a `CurveIterator` for a Tasks Demand
     #[must_use]
     pub const fn new(task: Task) -> Self {
-        TaskDemandIterator { task, next_job: 0 }
+        TaskDemandIterator {
+            task,
+            next_job: 0,
+            limit: WindowEnd::Infinite,
+            previous_end: TimeUnit::ZERO,
+        }
+    }
+
+    /// Create a `CurveIterator` for a Tasks Demand
+    /// that stops before the first window ending after `limit`
+    ///
+    /// Equivalent to `TaskDemandIterator::new(task).take_while(|window| window.end <= limit)`
+    #[must_use]
+    pub const fn new_bounded(task: Task, limit: TimeUnit) -> Self {
+        TaskDemandIterator {
+            task,
+            next_job: 0,
+            limit: WindowEnd::Finite(limit),
+            previous_end: TimeUnit::ZERO,
+        }
     }
 }
 
@@ -29,14 +52,20 @@ impl CurveIterator for TaskDemandIterator {
 
     fn next_window(&mut self) -> Option<Window<<Self::CurveKind as CurveType>::WindowKind>> {
         // using checked arithmetic to stop on overflow
-        let start = self
-            .task
-            .offset
-            .as_unit()
-            .checked_add(self.next_job.checked_mul(self.task.interval.as_unit())?)?;
-        let end = UnitNumber::checked_add(start, self.task.demand.as_unit())?;
+        let release = self.task.checked_job_release(self.next_job)?;
         self.next_job = self.next_job.checked_add(1)?;
-        Some(Window::new(TimeUnit::from(start), TimeUnit::from(end)))
+
+        // the whole demand of a job arriving before the origin is placed at the origin,
+        // with jitter the job may be released before the previous job's demand is served,
+        // so queue it behind that demand
+        let start = release.max(self.previous_end);
+        let end = start.checked_add(self.task.demand)?;
+        self.previous_end = end;
+
+        let window = Window::new(start, end);
+
+        // later windows end even later, so all of them are beyond the limit
+        (window.end <= self.limit).then(|| window)
     }
 }
 
@@ -27,6 +27,8 @@
 // we require alloc though
 #![no_std]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub mod time;
 
@@ -38,3 +40,11 @@ pub mod task;
 pub mod window;
 
 pub mod iterators;
+
+#[cfg(feature = "std")]
+pub mod io;
+
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+pub mod prelude;
@@ -0,0 +1,19 @@
+//! Module re-exporting the commonly used types and traits
+//!
+//! Intended to be glob imported with `use rta_for_fps_lib::prelude::*;`
+
+pub use crate::curve::curve_types::{CurveType, UnspecifiedCurve};
+pub use crate::curve::{AggregateExt, Curve};
+pub use crate::iterators::CurveIterator;
+pub use crate::server::{
+    ActualServerExecution, AggregatedServerDemand, AvailableServerExecution,
+    ConstrainedServerDemand, HigherPriorityServerDemand, HigherPriorityServerExecution, Server,
+    ServerIdle, ServerKind, ServerProperties, UnconstrainedServerExecution,
+};
+pub use crate::system::System;
+pub use crate::task::curve_types::{
+    ActualTaskExecution, AvailableTaskExecution, HigherPriorityTaskDemand, TaskDemand,
+};
+pub use crate::task::Task;
+pub use crate::time::TimeUnit;
+pub use crate::window::{Demand, Overlap, Supply, Window, WindowEnd};
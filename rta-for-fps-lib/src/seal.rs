@@ -2,8 +2,9 @@
 
 use crate::curve::curve_types::UnspecifiedCurve;
 use crate::server::{
-    ActualServerExecution, AggregatedServerDemand, ConstrainedServerDemand,
-    HigherPriorityServerDemand, HigherPriorityServerExecution, UnconstrainedServerExecution,
+    ActualServerExecution, AggregatedServerDemand, AvailableServerExecution,
+    ConstrainedServerDemand, HigherPriorityServerDemand, HigherPriorityServerExecution, ServerIdle,
+    UnconstrainedServerExecution,
 };
 use crate::task::curve_types::{
     ActualTaskExecution, AvailableTaskExecution, HigherPriorityTaskDemand, TaskDemand,
@@ -29,7 +30,9 @@ impl Seal for ConstrainedServerDemand {}
 impl Seal for HigherPriorityServerDemand {}
 impl Seal for HigherPriorityServerExecution {}
 impl Seal for UnconstrainedServerExecution {}
+impl Seal for AvailableServerExecution {}
 impl Seal for ActualServerExecution {}
+impl Seal for ServerIdle {}
 
 // Task Curves
 impl Seal for TaskDemand {}
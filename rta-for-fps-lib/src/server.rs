@@ -5,13 +5,15 @@
 use crate::curve::AggregateExt;
 
 use crate::curve::curve_types::CurveType;
-use crate::iterators::curve::AggregationIterator;
+use crate::iterators::curve::{AggregationIterator, IterCurveWrapper};
 use crate::iterators::server::constrained_demand::ConstrainedServerDemandIterator;
 use crate::iterators::task::TaskDemandIterator;
 use crate::iterators::{CurveIterator, ReclassifyIterator};
+use crate::system::System;
 use crate::task::Task;
-use crate::time::TimeUnit;
-use crate::window::{Demand, Window};
+use crate::time::{TimeUnit, UnitNumber};
+use crate::window::{BudgetGroups, Demand, Window};
+use alloc::vec::Vec;
 
 /// Marker Type for aggregated server demand curve
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
@@ -33,12 +35,23 @@ pub struct HigherPriorityServerExecution;
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
 pub struct UnconstrainedServerExecution;
 
+/// Marker Type for the execution available to a server,
+/// before it is constrained by the servers demand and capacity
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub struct AvailableServerExecution;
+
 /// Marker Type for constrained server execution curve
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
 pub struct ActualServerExecution;
 
+/// Marker Type for the idle time of a server,
+/// the gaps in its actual execution
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub struct ServerIdle;
+
 /// Type Representing a Server
-/// with a given set of tasks,
+///
+/// A Server has a given set of tasks,
 /// a capacity for fulfilling demand,
 /// a replenishment interval for how
 /// often the capacity is restored
@@ -54,6 +67,57 @@ pub struct Server<'a> {
     pub properties: ServerProperties,
 }
 
+/// A Server owning its Tasks,
+/// see [`OwnedSystem`](crate::system::OwnedSystem)
+#[derive(Debug, Clone)]
+pub struct OwnedServer {
+    /// The Tasks that produce Demand for this Server
+    /// Sorted by priority with lower index equalling higher priority
+    pub tasks: Vec<Task>,
+    /// The properties of the Server
+    pub properties: ServerProperties,
+}
+
+impl OwnedServer {
+    /// Scale the time of the Server by `factor`,
+    /// scaling its properties using [`ServerProperties::scale_time`]
+    /// and its Tasks using [`Task::scale_time`]
+    ///
+    /// # Panics
+    /// When `factor` is 0
+    #[must_use]
+    pub fn scale_time(self, factor: UnitNumber) -> Self {
+        let properties = self.properties.scale_time(factor);
+
+        OwnedServer {
+            tasks: self
+                .tasks
+                .into_iter()
+                .map(|task| task.scale_time(factor))
+                .collect(),
+            properties,
+        }
+    }
+
+    /// Get a Server borrowing the Tasks of this `OwnedServer`
+    #[must_use]
+    pub fn as_server(&self) -> Server<'_> {
+        Server {
+            tasks: &self.tasks,
+            properties: self.properties,
+        }
+    }
+}
+
+impl From<&Server<'_>> for OwnedServer {
+    fn from(server: &Server<'_>) -> Self {
+        OwnedServer {
+            tasks: server.tasks.to_vec(),
+            properties: server.properties,
+        }
+    }
+}
+
 /// The Properties of a server
 #[derive(Debug, Clone, Copy)]
 pub struct ServerProperties {
@@ -63,6 +127,198 @@ pub struct ServerProperties {
     pub interval: TimeUnit,
     /// How the available capacity behaves
     pub server_type: ServerKind,
+    /// The offset of the replenishments from multiples of the interval,
+    /// taken modulo the interval
+    ///
+    /// The time before the first replenishment forms a shortened first budget group,
+    /// which has no budget as the server is not replenished before its first replenishment,
+    /// see [`ServerProperties::group_capacity`]
+    pub phase: TimeUnit,
+}
+
+impl ServerProperties {
+    /// Create the Properties of a Periodic Server, also known as Polling Server,
+    /// see [`ServerKind::Periodic`]
+    ///
+    /// # Example
+    /// ```
+    /// # use rta_for_fps_lib::server::{ServerKind, ServerProperties};
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// let properties = ServerProperties::polling(TimeUnit::from(1), TimeUnit::from(4));
+    /// assert!(matches!(properties.server_type, ServerKind::Periodic));
+    /// ```
+    #[must_use]
+    pub const fn polling(capacity: TimeUnit, interval: TimeUnit) -> Self {
+        ServerProperties {
+            capacity,
+            interval,
+            server_type: ServerKind::Periodic,
+            phase: TimeUnit::ZERO,
+        }
+    }
+
+    /// Create the Properties of a Deferrable Server,
+    /// see [`ServerKind::Deferrable`]
+    ///
+    /// # Example
+    /// ```
+    /// # use rta_for_fps_lib::server::{ServerKind, ServerProperties};
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// let properties = ServerProperties::deferrable(TimeUnit::from(3), TimeUnit::from(10));
+    /// assert!(matches!(properties.server_type, ServerKind::Deferrable));
+    /// ```
+    #[must_use]
+    pub const fn deferrable(capacity: TimeUnit, interval: TimeUnit) -> Self {
+        ServerProperties {
+            capacity,
+            interval,
+            server_type: ServerKind::Deferrable,
+            phase: TimeUnit::ZERO,
+        }
+    }
+
+    /// Create the Properties of a Background Server,
+    /// see [`ServerKind::Background`]
+    ///
+    /// The Server has no capacity and an interval of one,
+    /// which is neutral with regard to hyper periods and the utilization
+    ///
+    /// # Example
+    /// ```
+    /// # use rta_for_fps_lib::server::{ServerKind, ServerProperties};
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// let properties = ServerProperties::background();
+    /// assert!(matches!(properties.server_type, ServerKind::Background));
+    /// assert_eq!(properties.capacity, TimeUnit::ZERO);
+    /// ```
+    #[must_use]
+    pub const fn background() -> Self {
+        ServerProperties {
+            capacity: TimeUnit::ZERO,
+            interval: TimeUnit::ONE,
+            server_type: ServerKind::Background,
+            phase: TimeUnit::ZERO,
+        }
+    }
+
+    /// Set the phase of the Server Properties, see [`ServerProperties::phase`]
+    ///
+    /// # Example
+    /// ```
+    /// # use rta_for_fps_lib::server::ServerProperties;
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// let properties =
+    ///     ServerProperties::deferrable(TimeUnit::from(3), TimeUnit::from(10)).with_phase(TimeUnit::from(4));
+    ///
+    /// assert_eq!(properties.budget_group(TimeUnit::from(3)), 0);
+    /// assert_eq!(properties.budget_group(TimeUnit::from(4)), 1);
+    /// assert_eq!(properties.group_start(1), TimeUnit::from(4));
+    /// assert_eq!(properties.group_end(1), TimeUnit::from(14));
+    ///
+    /// // the server is first replenished at 4
+    /// assert_eq!(properties.group_capacity(0), TimeUnit::ZERO);
+    /// assert_eq!(properties.group_capacity(1), TimeUnit::from(3));
+    /// ```
+    #[must_use]
+    pub const fn with_phase(mut self, phase: TimeUnit) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// The budget groups of the Server, delimited by its replenishments
+    pub(crate) fn budget_groups(&self) -> BudgetGroups {
+        BudgetGroups::new(self.interval, self.phase)
+    }
+
+    /// Calculate the budget group `time` falls into,
+    /// the time before the first replenishment falls into the shortened group 0
+    ///
+    /// # Panics
+    /// When the interval is zero
+    #[must_use]
+    pub fn budget_group(&self, time: TimeUnit) -> UnitNumber {
+        self.budget_groups().group(time)
+    }
+
+    /// Calculate the start of the budget group `group`,
+    /// the shortened first group starts at zero
+    ///
+    /// # Panics
+    /// When the interval is zero
+    #[must_use]
+    pub fn group_start(&self, group: UnitNumber) -> TimeUnit {
+        self.budget_groups().start(group)
+    }
+
+    /// Calculate the end of the budget group `group`,
+    /// which is the start of the next group
+    ///
+    /// # Panics
+    /// When the interval is zero
+    #[must_use]
+    pub fn group_end(&self, group: UnitNumber) -> TimeUnit {
+        self.budget_groups().end(group)
+    }
+
+    /// Calculate the budget available in the budget group `group`
+    ///
+    /// The shortened first group of a phased Server lies before its first replenishment
+    /// and therefore has no budget, every other group has the full capacity
+    ///
+    /// # Panics
+    /// When the interval is zero
+    #[must_use]
+    pub fn group_capacity(&self, group: UnitNumber) -> TimeUnit {
+        if self.budget_groups().is_shortened(group) {
+            TimeUnit::ZERO
+        } else {
+            self.capacity
+        }
+    }
+
+    /// The capacity the Server has to be guaranteed every interval
+    ///
+    /// A Background Server is not guaranteed any capacity regardless of its configured capacity
+    /// and is therefore exempt from the capacity check
+    pub(crate) const fn guaranteed_capacity(&self) -> TimeUnit {
+        match self.server_type {
+            ServerKind::Background => TimeUnit::ZERO,
+            ServerKind::Deferrable | ServerKind::Periodic => self.capacity,
+        }
+    }
+
+    /// Calculate the number of budget groups starting before `time`
+    ///
+    /// # Panics
+    /// When the interval is zero
+    #[must_use]
+    pub fn groups_before(&self, time: TimeUnit) -> UnitNumber {
+        if time == TimeUnit::ZERO {
+            0
+        } else {
+            self.budget_group(time - TimeUnit::ONE) + 1
+        }
+    }
+
+    /// Scale the time of the Server Properties by `factor`,
+    /// multiplying capacity, interval and phase
+    ///
+    /// # Panics
+    /// When `factor` is 0, as the Server would have a zero interval
+    #[must_use]
+    pub fn scale_time(self, factor: UnitNumber) -> Self {
+        assert!(
+            factor > 0,
+            "Can't scale the time of Server Properties by 0!"
+        );
+
+        ServerProperties {
+            capacity: self.capacity * factor,
+            interval: self.interval * factor,
+            server_type: self.server_type,
+            phase: self.phase * factor,
+        }
+    }
 }
 
 /// The Type of a Server
@@ -74,6 +330,12 @@ pub enum ServerKind {
     /// Indicates that the Server is a Periodic Server
     /// as described/defined in Section 5.2 Paragraph 4 of the paper
     Periodic,
+    /// Indicates that the Server is a Background Server,
+    /// it is not limited by its capacity but executes whenever no higher priority Server executes
+    ///
+    /// As the Server is not guaranteed any capacity it is exempt from the capacity check
+    /// and its capacity is ignored, [`ServerProperties::background`] sets it to zero
+    Background,
 }
 
 /**
@@ -121,18 +383,119 @@ impl<'a> Server<'a> {
                 capacity,
                 interval,
                 server_type,
+                phase: TimeUnit::ZERO,
             },
         }
     }
 
+    /// Create a new Deferrable Server with the given Tasks,
+    /// see [`ServerKind::Deferrable`]
+    ///
+    /// ```
+    /// # use rta_for_fps_lib::server::{Server, ServerKind};
+    /// # use rta_for_fps_lib::task::Task;
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// let tasks = [Task::new(1, 4, 0)];
+    ///
+    /// let server = Server::deferrable(&tasks, 3.into(), 10.into());
+    ///
+    /// assert_eq!(server.capacity(), TimeUnit::from(3));
+    /// assert_eq!(server.interval(), TimeUnit::from(10));
+    /// assert!(matches!(server.kind(), ServerKind::Deferrable));
+    /// ```
+    #[must_use]
+    pub const fn deferrable(tasks: &'a [Task], capacity: TimeUnit, interval: TimeUnit) -> Self {
+        Server::new(tasks, capacity, interval, ServerKind::Deferrable)
+    }
+
+    /// Create a new Periodic Server with the given Tasks,
+    /// see [`ServerKind::Periodic`]
+    ///
+    /// ```
+    /// # use rta_for_fps_lib::server::{Server, ServerKind};
+    /// # use rta_for_fps_lib::task::Task;
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// let tasks = [Task::new(1, 20, 0)];
+    ///
+    /// let server = Server::periodic(&tasks, 1.into(), 20.into());
+    ///
+    /// assert_eq!(server.capacity(), TimeUnit::from(1));
+    /// assert_eq!(server.interval(), TimeUnit::from(20));
+    /// assert!(matches!(server.kind(), ServerKind::Periodic));
+    /// ```
+    #[must_use]
+    pub const fn periodic(tasks: &'a [Task], capacity: TimeUnit, interval: TimeUnit) -> Self {
+        Server::new(tasks, capacity, interval, ServerKind::Periodic)
+    }
+
     /// Get a a reference to a slice of the Servers contained Tasks
     #[must_use]
     pub const fn as_tasks(&self) -> &'a [Task] {
         self.tasks
     }
 
-    /// Calculate the aggregated demand Curve of a given Server up to a specified limit
+    /// Get the capacity of the Server, see [`ServerProperties::capacity`]
+    #[must_use]
+    pub const fn capacity(&self) -> TimeUnit {
+        self.properties.capacity
+    }
+
+    /// Get the interval of the Server, see [`ServerProperties::interval`]
+    #[must_use]
+    pub const fn interval(&self) -> TimeUnit {
+        self.properties.interval
+    }
+
+    /// Get the kind of the Server, see [`ServerProperties::server_type`]
+    #[must_use]
+    pub const fn kind(&self) -> ServerKind {
+        self.properties.server_type
+    }
+
+    /// Scale the time of the Server and its Tasks by `factor`,
+    /// see [`OwnedServer::scale_time`]
+    ///
+    /// As the Server only borrows its Tasks they can't be scaled in place,
+    /// the scaled Server owns copies of them
+    ///
+    /// # Panics
+    /// When `factor` is 0, see [`ServerProperties::scale_time`]
+    #[must_use]
+    pub fn scale_time(&self, factor: UnitNumber) -> OwnedServer {
+        OwnedServer::from(self).scale_time(factor)
+    }
+
+    /// Create a Server with the same properties as this Server but with different Tasks,
+    /// e.g. for what-if analysis
+    ///
+    /// The returned Server only borrows `tasks` and not this Server
+    #[must_use]
+    pub const fn with_tasks<'b>(&self, tasks: &'b [Task]) -> Server<'b> {
+        Server {
+            tasks,
+            properties: self.properties,
+        }
+    }
+
+    /// Calculate the demand of all jobs of the Server's Tasks that arrive before `t`
+    ///
+    /// This is the request bound function `Σ ⌈(t - offset) / interval⌉ * demand`
+    /// over all Tasks, computed in closed form without using the demand curves,
+    /// see [`Task::released_demand_before`]
+    #[must_use]
+    pub fn aggregated_demand_at(&self, t: TimeUnit) -> TimeUnit {
+        self.tasks
+            .iter()
+            .map(|task| task.released_demand_before(t))
+            .sum()
+    }
+
+    /// Calculate the aggregated demand Curve of a given Server
     /// As defined in Definition 11. in the paper
+    ///
+    /// As the jobs of the Tasks repeat forever the returned `CurveIterator` is infinite,
+    /// so it needs to be bounded before collecting it,
+    /// see [`Server::aggregated_demand_curve_iter_up_to`]
     #[must_use]
     pub fn aggregated_demand_curve_iter(&self) -> AggregatedTaskDemand {
         AggregatedTaskDemand(
@@ -143,6 +506,26 @@ impl<'a> Server<'a> {
         )
     }
 
+    /// Calculate the aggregated demand Curve of a given Server
+    /// stopping before the first window ending after `limit`
+    ///
+    /// Unlike [`Server::aggregated_demand_curve_iter`] the returned `CurveIterator` is finite
+    #[must_use]
+    pub fn aggregated_demand_curve_iter_up_to(
+        &self,
+        limit: TimeUnit,
+    ) -> impl CurveIterator<CurveKind = AggregatedServerDemand> + Clone {
+        let bounded = self
+            .aggregated_demand_curve_iter()
+            .take_while_curve(move |window| window.end <= limit);
+
+        unsafe {
+            // Safety:
+            // a prefix of a CurveIterator upholds the CurveIterator invariants
+            IterCurveWrapper::new(bounded)
+        }
+    }
+
     /// Calculate the constrained demand curve
     #[must_use]
     pub fn constraint_demand_curve_iter(&self) -> ConstrainedDemand {
@@ -151,4 +534,61 @@ impl<'a> Server<'a> {
             self.aggregated_demand_curve_iter(),
         ))
     }
+
+    /// Calculate the WCRT of all Tasks of the server with priority `server_index`
+    /// using [`Task::original_worst_case_response_time`]
+    ///
+    /// Returns the index of the Task with the largest WCRT together with its WCRT,
+    /// on a tie the Task with the higher priority (lower index) is returned,
+    /// or `None` if the Server has no Tasks
+    ///
+    /// # Panics
+    /// When sanity checks fail
+    #[must_use]
+    pub fn original_worst_case_response_time(
+        system: &System,
+        server_index: usize,
+        arrival_before: TimeUnit,
+    ) -> Option<(usize, TimeUnit)> {
+        Server::worst_case_response_time_impl(system, server_index, |task_index| {
+            Task::original_worst_case_response_time(
+                system,
+                server_index,
+                task_index,
+                arrival_before,
+            )
+        })
+    }
+
+    /// Same as `original_worst_case_response_time` in most aspects, but uses [`Task::fixed_worst_case_response_time`]
+    ///
+    /// # Panics
+    /// When sanity checks fail
+    #[must_use]
+    pub fn fixed_worst_case_response_time(
+        system: &System,
+        server_index: usize,
+        arrival_before: TimeUnit,
+    ) -> Option<(usize, TimeUnit)> {
+        Server::worst_case_response_time_impl(system, server_index, |task_index| {
+            Task::fixed_worst_case_response_time(system, server_index, task_index, arrival_before)
+        })
+    }
+
+    /// Find the Task with the largest WCRT as calculated by `wcrt`
+    fn worst_case_response_time_impl<F>(
+        system: &System,
+        server_index: usize,
+        wcrt: F,
+    ) -> Option<(usize, TimeUnit)>
+    where
+        F: Fn(usize) -> TimeUnit,
+    {
+        (0..system.as_servers()[server_index].as_tasks().len())
+            .map(|task_index| (task_index, wcrt(task_index)))
+            .fold(None, |worst, (task_index, response_time)| match worst {
+                Some((_, worst_response_time)) if worst_response_time >= response_time => worst,
+                _ => Some((task_index, response_time)),
+            })
+    }
 }
@@ -0,0 +1,62 @@
+//! Module for [proptest] strategies generating valid Windows and Curves
+//!
+//! Allows property testing the curve operations,
+//! only available with the `proptest` feature
+
+use alloc::vec::Vec;
+
+use proptest::collection::btree_set;
+use proptest::strategy::{Just, Strategy};
+
+use crate::curve::curve_types::CurveType;
+use crate::curve::Curve;
+use crate::time::TimeUnit;
+use crate::window::window_types::WindowType;
+use crate::window::Window;
+
+/// Create a Strategy generating non-empty finite Windows contained in `[0, max_time]`
+///
+/// # Panics
+/// When `max_time` is [`TimeUnit::ZERO`], as no non-empty Window fits
+#[must_use = "a Strategy generates nothing unless used in a proptest"]
+pub fn window_strategy<W: WindowType>(max_time: TimeUnit) -> impl Strategy<Value = Window<W>> {
+    assert!(
+        max_time > TimeUnit::ZERO,
+        "No non-empty window fits into [0, 0]"
+    );
+
+    let max_time = max_time.as_unit();
+
+    (0..max_time)
+        .prop_flat_map(move |start| (Just(start), start + 1..=max_time))
+        .prop_map(|(start, end)| Window::new(start, end))
+}
+
+/// Create a Strategy generating Curves of at most `max_windows` finite Windows contained in `[0, max_time]`
+///
+/// The generated Curves uphold the Curve invariants,
+/// their windows are ordered, non-empty and neither overlap nor are adjacent.
+/// Fewer windows are generated when `[0, max_time]` has too few points for `max_windows`
+#[must_use = "a Strategy generates nothing unless used in a proptest"]
+pub fn curve_strategy<C: CurveType>(
+    max_windows: usize,
+    max_time: TimeUnit,
+) -> impl Strategy<Value = Curve<C>> {
+    // only max_time + 1 distinct points exist, asking for more would never succeed
+    let max_points = (2 * max_windows).min(max_time.as_unit() + 1);
+
+    // distinct sorted points, pairing them up yields windows that are non-empty and not adjacent
+    btree_set(0..=max_time.as_unit(), 0..=max_points).prop_map(|points| {
+        let points: Vec<_> = points.into_iter().collect();
+
+        let windows = points
+            .chunks_exact(2)
+            .map(|pair| Window::new(pair[0], pair[1]))
+            .collect();
+
+        // Safety:
+        // the points are distinct and sorted, so the windows are ordered, non-empty
+        // and there is a gap between the end of one window and the start of the next
+        unsafe { Curve::from_windows_unchecked(windows) }
+    })
+}
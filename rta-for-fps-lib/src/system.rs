@@ -1,20 +1,31 @@
 //! Module for the System type
 
-use crate::curve::AggregateExt;
-use crate::iterators::curve::{AggregationIterator, CapacityCheckIterator, InverseCurveIterator};
+use crate::curve::{AggregateExt, Curve};
+use crate::iterators::curve::{
+    AggregationIterator, CapacityCheckIterator, CapacityError, InverseCurveIterator,
+    TryCapacityCheckIterator,
+};
 
 use crate::server::{
-    ActualServerExecution, ConstrainedDemand, ConstrainedServerDemand, HigherPriorityServerDemand,
-    HigherPriorityServerExecution, Server, UnconstrainedServerExecution,
+    ActualServerExecution, AvailableServerExecution, ConstrainedDemand, ConstrainedServerDemand,
+    HigherPriorityServerDemand, HigherPriorityServerExecution, OwnedServer, Server, ServerIdle,
+    ServerKind, ServerProperties, UnconstrainedServerExecution,
 };
 
 use crate::curve::curve_types::CurveType;
 use crate::iterators::server::actual_execution::ActualServerExecutionIterator;
-use crate::iterators::{CurveIterator, EitherCurveIterator, ReclassifyIterator};
-use crate::time::TimeUnit;
-use crate::window::Window;
+use crate::iterators::{
+    first_window_overlap, CurveIterator, EitherCurveIterator, ReclassifyIterator,
+    ReinterpretIterator,
+};
+use crate::task::Task;
+use crate::time::{TimeUnit, UnitNumber};
+use crate::window::{Window, WindowEnd};
 use alloc::boxed::Box;
+use alloc::rc::Rc;
 use alloc::vec::Vec;
+use core::cell::Cell;
+use core::fmt::{Debug, Display, Formatter};
 
 /// Type representing a System of Servers
 #[derive(Debug)]
@@ -22,6 +33,187 @@ pub struct System<'a> {
     /// The Servers of the System
     servers: &'a [Server<'a>],
 }
+
+/// A System owning its Servers and their Tasks,
+/// avoiding the borrows of [`System`] when constructing Systems programmatically
+///
+/// Use [`OwnedSystem::view`] to analyse it
+#[derive(Debug, Clone)]
+pub struct OwnedSystem {
+    /// The Servers of the System, indexed by their priority
+    servers: Vec<OwnedServer>,
+}
+
+/// The Servers of an [`OwnedSystem`] borrowing its Tasks,
+/// see [`OwnedSystem::view`]
+#[derive(Debug)]
+pub struct SystemView<'a> {
+    /// The borrowing Servers, indexed by their priority
+    servers: Vec<Server<'a>>,
+}
+
+impl SystemView<'_> {
+    /// Get a System borrowing the Servers of this view
+    #[must_use]
+    pub fn as_system(&self) -> System<'_> {
+        System::new(&self.servers)
+    }
+}
+
+impl OwnedSystem {
+    /// Create a System owning `servers`, indexed by their priority
+    #[must_use]
+    pub const fn new(servers: Vec<OwnedServer>) -> Self {
+        OwnedSystem { servers }
+    }
+
+    /// Get the Servers of the System, indexed by their priority
+    #[must_use]
+    pub fn as_owned_servers(&self) -> &[OwnedServer] {
+        &self.servers
+    }
+
+    /// Take the Servers out of the System, indexed by their priority
+    #[must_use]
+    pub fn into_owned_servers(self) -> Vec<OwnedServer> {
+        self.servers
+    }
+
+    /// Get Servers borrowing the Tasks of this `OwnedSystem`,
+    /// use [`SystemView::as_system`] to get a System over them
+    #[must_use]
+    pub fn view(&self) -> SystemView<'_> {
+        SystemView {
+            servers: self.servers.iter().map(OwnedServer::as_server).collect(),
+        }
+    }
+
+    /// See [`System::analysis_end`]
+    #[must_use]
+    pub fn analysis_end(&self, server_index: usize) -> TimeUnit {
+        self.view().as_system().analysis_end(server_index)
+    }
+
+    /// See [`Task::original_worst_case_response_time`]
+    ///
+    /// # Panics
+    /// When sanity checks fail
+    #[must_use]
+    pub fn original_worst_case_response_time(
+        &self,
+        server_index: usize,
+        task_index: usize,
+        arrival_before: TimeUnit,
+    ) -> TimeUnit {
+        Task::original_worst_case_response_time(
+            &self.view().as_system(),
+            server_index,
+            task_index,
+            arrival_before,
+        )
+    }
+
+    /// See [`Task::fixed_worst_case_response_time`]
+    ///
+    /// # Panics
+    /// When sanity checks fail
+    #[must_use]
+    pub fn fixed_worst_case_response_time(
+        &self,
+        server_index: usize,
+        task_index: usize,
+        arrival_before: TimeUnit,
+    ) -> TimeUnit {
+        Task::fixed_worst_case_response_time(
+            &self.view().as_system(),
+            server_index,
+            task_index,
+            arrival_before,
+        )
+    }
+
+    /// See [`System::schedulability_report`]
+    #[must_use]
+    pub fn schedulability_report(&self) -> SchedulabilityReport {
+        self.view().as_system().schedulability_report()
+    }
+
+    /// See [`System::response_time_matrix`]
+    ///
+    /// # Panics
+    /// When sanity checks fail
+    #[must_use]
+    pub fn response_time_matrix<F>(
+        &self,
+        arrival_before: F,
+    ) -> Vec<Vec<Result<TimeUnit, CapacityError>>>
+    where
+        F: Fn(usize) -> TimeUnit,
+    {
+        self.view().as_system().response_time_matrix(arrival_before)
+    }
+}
+
+impl From<Vec<OwnedServer>> for OwnedSystem {
+    fn from(servers: Vec<OwnedServer>) -> Self {
+        OwnedSystem::new(servers)
+    }
+}
+
+impl From<&System<'_>> for OwnedSystem {
+    fn from(system: &System<'_>) -> Self {
+        OwnedSystem::new(system.as_servers().iter().map(OwnedServer::from).collect())
+    }
+}
+
+/// Builder owning the Servers of a [`System`],
+/// for constructing a System incrementally
+///
+/// ```
+/// # use rta_for_fps_lib::server::{Server, ServerKind};
+/// # use rta_for_fps_lib::system::SystemBuilder;
+/// # use rta_for_fps_lib::task::Task;
+/// let tasks_s1 = [Task::new(1, 4, 0)];
+/// let tasks_s2 = [Task::new(1, 5, 0), Task::new(2, 8, 0)];
+/// let tasks_s3 = [Task::new(1, 20, 0)];
+///
+/// let mut builder = SystemBuilder::new();
+/// builder
+///     .add_server(Server::new(&tasks_s1, 3.into(), 10.into(), ServerKind::Deferrable))
+///     .add_server(Server::new(&tasks_s2, 2.into(), 4.into(), ServerKind::Deferrable))
+///     .add_server(Server::new(&tasks_s3, 1.into(), 20.into(), ServerKind::Periodic));
+///
+/// let system = builder.build();
+///
+/// assert_eq!(system.as_servers().len(), 3);
+/// ```
+#[derive(Debug, Default)]
+pub struct SystemBuilder<'a> {
+    /// The Servers added so far, indexed by their priority
+    servers: Vec<Server<'a>>,
+}
+
+impl<'a> SystemBuilder<'a> {
+    /// Create a new `SystemBuilder` without any Servers
+    #[must_use]
+    pub const fn new() -> Self {
+        SystemBuilder {
+            servers: Vec::new(),
+        }
+    }
+
+    /// Add a Server with a lower priority than all previously added Servers
+    pub fn add_server(&mut self, server: Server<'a>) -> &mut Self {
+        self.servers.push(server);
+        self
+    }
+
+    /// Create a System borrowing the Servers of the builder
+    #[must_use]
+    pub fn build(&self) -> System<'_> {
+        System::new(&self.servers)
+    }
+}
 /**
 A `CurveIterator` over a servers aggregated higher priority demand
 */
@@ -50,11 +242,11 @@ A `CurveIterator` over the Aggregated Higher Priority Execution of a Server
 #[derive(Clone, Debug)]
 #[allow(clippy::type_complexity)]
 pub struct AggregatedHPExecution(
-    ReclassifyIterator<
+    ReinterpretIterator<
         AggregationIterator<
             EitherCurveIterator<
                 FixedActualExecution,
-                ReclassifyIterator<Box<AggregatedHPExecution>, ActualServerExecution>,
+                ReinterpretIterator<Box<AggregatedHPExecution>, ActualServerExecution>,
             >,
             <ActualServerExecution as CurveType>::WindowKind,
         >,
@@ -150,6 +342,251 @@ impl CurveIterator for FixedActualExecution {
     }
 }
 
+/**
+A `CurveIterator` over a capacity checked unconstrained execution,
+recording the error when a group lacks capacity
+
+As the actual execution calculation expects the supply to outlast the demand,
+the curve is ended with an infinite placeholder window once the error is recorded,
+the window calculated from it is never returned by [`TryActualExecution`]
+
+Clones share where the error is recorded
+*/
+#[derive(Debug, Clone)]
+pub(crate) struct CapacityErrorRecorder<UE> {
+    /// the fallible capacity check
+    iter: TryCapacityCheckIterator<<UnconstrainedServerExecution as CurveType>::WindowKind, UE>,
+    /// where to record the encountered error
+    error: Rc<Cell<Option<CapacityError>>>,
+    /// the end of the last returned window
+    last_end: TimeUnit,
+}
+
+impl<UE> CurveIterator for CapacityErrorRecorder<UE>
+where
+    UE: CurveIterator<CurveKind = UnconstrainedServerExecution>,
+{
+    type CurveKind = UnconstrainedServerExecution;
+
+    fn next_window(&mut self) -> Option<Window<<Self::CurveKind as CurveType>::WindowKind>> {
+        if self.error.get().is_some() {
+            return None;
+        }
+
+        match self.iter.next_window() {
+            Ok(next) => {
+                if let Some(WindowEnd::Finite(end)) = next.as_ref().map(|window| window.end) {
+                    self.last_end = end;
+                }
+                next
+            }
+            Err(error) => {
+                self.error.set(Some(error));
+                Some(Window::new(
+                    self.last_end + TimeUnit::ONE,
+                    WindowEnd::Infinite,
+                ))
+            }
+        }
+    }
+}
+
+/**
+An iterator over a Servers actual execution,
+reporting a server not being guaranteed its capacity as an error instead of panicking
+
+Calculated from the unconstrained execution `UE`,
+[`FixedUnconstrainedExecution`] for the fixed algorithm
+or [`OriginalUnconstrainedExecution`] for the original algorithm
+*/
+#[derive(Debug, Clone)]
+pub struct TryActualExecution<UE = FixedUnconstrainedExecution> {
+    /// the actual execution calculated from the capacity checked unconstrained execution
+    pub(crate) iter: ActualServerExecutionIterator<CapacityErrorRecorder<UE>, ConstrainedDemand>,
+    /// the error recorded by the capacity check
+    pub(crate) error: Rc<Cell<Option<CapacityError>>>,
+}
+
+impl<UE> TryActualExecution<UE>
+where
+    UE: CurveIterator<CurveKind = UnconstrainedServerExecution>,
+{
+    /// Return the next window of the actual execution
+    ///
+    /// # Errors
+    /// When the server was not guaranteed its capacity in a processed group,
+    /// once returned the error is repeated on every later call
+    /// without advancing the actual execution any further
+    pub fn next_window(
+        &mut self,
+    ) -> Result<Option<Window<<ActualServerExecution as CurveType>::WindowKind>>, CapacityError>
+    {
+        if let Some(error) = self.error.get() {
+            return Err(error);
+        }
+
+        let next = self.iter.next_window();
+        self.error.get().map_or(Ok(next), Err)
+    }
+}
+
+/**
+A `CurveIterator` over a [`TryActualExecution`] ending at the first [`CapacityError`],
+the error stays recorded in the `TryActualExecution`
+
+Clones share where the error is recorded
+*/
+#[derive(Debug, Clone)]
+pub(crate) struct UntilCapacityError<UE>(pub(crate) TryActualExecution<UE>);
+
+impl<UE> CurveIterator for UntilCapacityError<UE>
+where
+    UE: CurveIterator<CurveKind = UnconstrainedServerExecution> + Debug,
+{
+    type CurveKind = ActualServerExecution;
+
+    fn next_window(&mut self) -> Option<Window<<Self::CurveKind as CurveType>::WindowKind>> {
+        self.0.next_window().ok().flatten()
+    }
+}
+
+/// Summary of the schedulability of a System, see [`System::schedulability_report`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SchedulabilityReport {
+    /// the combined utilization of the Systems Servers
+    pub utilization: Utilization,
+    /// the reports of the Systems Servers indexed by priority
+    pub servers: Vec<ServerReport>,
+}
+
+impl SchedulabilityReport {
+    /// Check whether every Task of every Server was analysed and meets its deadline
+    #[must_use]
+    pub fn is_schedulable(&self) -> bool {
+        self.servers.iter().all(|server| {
+            server.tasks.iter().all(|task| {
+                matches!(
+                    task,
+                    TaskResult::Analysed {
+                        schedulable: true,
+                        ..
+                    }
+                )
+            })
+        })
+    }
+}
+
+/// The utilization of a set of Servers,
+/// expressed as the combined capacity the Servers have over an interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Utilization {
+    /// the combined capacity of the Servers over `interval`
+    pub capacity: TimeUnit,
+    /// the interval over which `capacity` is available, the hyper period of the Servers intervals
+    pub interval: TimeUnit,
+}
+
+impl Utilization {
+    /// Check whether the Servers demand more than the whole processor
+    #[must_use]
+    pub fn exceeds_one(&self) -> bool {
+        self.capacity > self.interval
+    }
+}
+
+/// The schedulability of a Server as part of a [`SchedulabilityReport`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ServerReport {
+    /// the time before which arriving jobs were analysed, see [`System::analysis_end`]
+    pub analysis_end: TimeUnit,
+    /// the results for the Servers Tasks indexed by priority
+    pub tasks: Vec<TaskResult>,
+}
+
+/// The result of analysing a Task as part of a [`SchedulabilityReport`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TaskResult {
+    /// The WCRT of the Task could be calculated
+    Analysed {
+        /// the WCRT of the Task
+        wcrt: TimeUnit,
+        /// the deadline of the Task, its interval
+        deadline: TimeUnit,
+        /// whether the WCRT does not exceed the deadline
+        schedulable: bool,
+    },
+    /// The Tasks Server was not guaranteed its capacity, so the WCRT could not be calculated
+    AnalysisFailed(CapacityError),
+}
+
+/// Report of the actual execution of two Servers overlapping non-trivially,
+/// see [`System::check_no_execution_overlap`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverlapReport {
+    /// the index of the higher priority Server
+    pub higher_priority_server: usize,
+    /// the index of the lower priority Server
+    pub lower_priority_server: usize,
+    /// the window of the higher priority Servers actual execution
+    pub higher_priority_window: Window<<ActualServerExecution as CurveType>::WindowKind>,
+    /// the window of the lower priority Servers actual execution
+    pub lower_priority_window: Window<<ActualServerExecution as CurveType>::WindowKind>,
+}
+
+impl Display for OverlapReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Execution of Server {} in {} overlaps execution of Server {} in {}!",
+            self.higher_priority_server,
+            self.higher_priority_window,
+            self.lower_priority_server,
+            self.lower_priority_window
+        )
+    }
+}
+
+/// Error for a priority order passed to [`System::reorder`] not being a permutation of the Servers indices
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderError {
+    /// The order does not contain exactly one index per Server
+    WrongLength {
+        /// the number of Servers in the System
+        expected: usize,
+        /// the number of indices in the order
+        actual: usize,
+    },
+    /// The order contains an index with no matching Server
+    IndexOutOfRange(usize),
+    /// The order contains an index more than once
+    DuplicateIndex(usize),
+}
+
+impl Display for OrderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            OrderError::WrongLength { expected, actual } => write!(
+                f,
+                "Order has {actual} indices, but the System has {expected} Servers!"
+            ),
+            OrderError::IndexOutOfRange(index) => {
+                write!(
+                    f,
+                    "Order contains index {index}, but there is no such Server!"
+                )
+            }
+            OrderError::DuplicateIndex(index) => {
+                write!(f, "Order contains index {index} more than once!")
+            }
+        }
+    }
+}
+
 impl<'a> System<'a> {
     /// Create a new System from a slice of Servers,
     /// indexed by their priority,
@@ -165,6 +602,77 @@ impl<'a> System<'a> {
         self.servers
     }
 
+    /// Create an [`OwnedSystem`] with the same Servers and Tasks,
+    /// but with the properties of the server with priority `server_index` transformed by `fun`
+    ///
+    /// Useful for sensitivity analysis e.g. sweeping the capacity of a Server
+    ///
+    /// # Panics
+    /// When there is no server with priority `server_index`
+    #[must_use]
+    pub fn map_server<F>(&self, server_index: usize, fun: F) -> OwnedSystem
+    where
+        F: FnOnce(ServerProperties) -> ServerProperties,
+    {
+        let mut servers: Vec<_> = self.servers.iter().map(OwnedServer::from).collect();
+        let server = &mut servers[server_index];
+        server.properties = fun(server.properties);
+        OwnedSystem::new(servers)
+    }
+
+    /// Create an [`OwnedSystem`] with the Servers reordered according to `order`,
+    /// the Server with priority `order[i]` in this System has priority `i` in the new System
+    ///
+    /// Useful for comparing priority assignments e.g. Rate-Monotonic and Deadline-Monotonic
+    ///
+    /// # Errors
+    /// When `order` is not a permutation of the Servers indices
+    pub fn reorder(&self, order: &[usize]) -> Result<OwnedSystem, OrderError> {
+        if order.len() != self.servers.len() {
+            return Err(OrderError::WrongLength {
+                expected: self.servers.len(),
+                actual: order.len(),
+            });
+        }
+
+        let mut seen = alloc::vec![false; self.servers.len()];
+        for &index in order {
+            match seen.get_mut(index) {
+                None => return Err(OrderError::IndexOutOfRange(index)),
+                Some(true) => return Err(OrderError::DuplicateIndex(index)),
+                Some(seen) => *seen = true,
+            }
+        }
+
+        Ok(OwnedSystem::new(
+            order
+                .iter()
+                .map(|&index| OwnedServer::from(&self.servers[index]))
+                .collect(),
+        ))
+    }
+
+    /// Calculate the Rate-Monotonic priority order of `servers`,
+    /// the indices of the Servers sorted by ascending interval
+    ///
+    /// Servers with equal intervals keep their relative order (stable by original index).
+    /// The result can be passed to [`System::reorder`].
+    #[must_use]
+    pub fn rate_monotonic_order(servers: &[Server]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..servers.len()).collect();
+        order.sort_by_key(|&index| servers[index].interval());
+        order
+    }
+
+    /// Calculate the Deadline-Monotonic priority order of `servers`
+    ///
+    /// Servers have implicit deadlines equal to their interval,
+    /// so this coincides with [`System::rate_monotonic_order`].
+    #[must_use]
+    pub fn deadline_monotonic_order(servers: &[Server]) -> Vec<usize> {
+        Self::rate_monotonic_order(servers)
+    }
+
     /// Calculate the aggregated higher priority demand curve
     /// by aggregating the aggregated demand curves of all Servers with higher priority
     /// (lower value) than `index`.
@@ -199,10 +707,9 @@ impl<'a> System<'a> {
                 self.fixed_actual_execution_curve_iter(server_index - 1),
             ));
             if server_index > 1 {
-                let curve = Box::new(
+                let curve = ReinterpretIterator::new(Box::new(
                     self.aggregated_higher_priority_actual_execution_curve_iter(server_index - 1),
-                )
-                .reclassify();
+                ));
                 curves.push(EitherCurveIterator::Right(curve));
             }
         }
@@ -231,13 +738,13 @@ impl<'a> System<'a> {
 
     /**
     For the server with index `server_index` calculate up to which point in time we need to perform the analysis
-    Replaces `system_wide_hyper_period` as that does not account for task offset
+    Replaces `system_wide_hyper_period` as that does not account for task offset and server phase
     */
     #[must_use]
     pub fn analysis_end(&self, server_index: usize) -> TimeUnit {
         let res = self.servers[..=server_index]
             .iter()
-            .map(|server| (server.properties.interval, TimeUnit::ZERO))
+            .map(|server| (server.properties.interval, server.properties.phase))
             .chain(self.servers.iter().flat_map(|server| {
                 server
                     .as_tasks()
@@ -250,6 +757,49 @@ impl<'a> System<'a> {
         res.0 + res.1
     }
 
+    /**
+    For every server yield its index, `system_wide_hyper_period` and `analysis_end`
+
+    As `analysis_end` extends the same hyper period by the largest task offset
+    the analysis end is never before the system wide hyper period
+    */
+    pub fn per_server_bounds(&self) -> impl Iterator<Item = (usize, TimeUnit, TimeUnit)> + '_ {
+        (0..self.servers.len()).map(move |server_index| {
+            (
+                server_index,
+                self.system_wide_hyper_period(server_index),
+                self.analysis_end(server_index),
+            )
+        })
+    }
+
+    /**
+    Same as `analysis_end` but extended by a caller-specified `margin`
+    */
+    #[must_use]
+    pub fn analysis_end_with_margin(&self, server_index: usize, margin: TimeUnit) -> TimeUnit {
+        self.analysis_end(server_index) + margin
+    }
+
+    /**
+    Calculate up to which point in time the analysis needs to be performed,
+    such that exactly the first `jobs` jobs of the task with index `task_index`
+    of the server with index `server_index` arrive before it
+
+    # Panics
+    When `jobs` is 0
+    */
+    #[must_use]
+    pub fn analysis_end_for_jobs(
+        &self,
+        server_index: usize,
+        task_index: usize,
+        jobs: UnitNumber,
+    ) -> TimeUnit {
+        assert!(jobs > 0, "Analysis needs to include at least one job!");
+        self.servers[server_index].as_tasks()[task_index].job_arrival(jobs)
+    }
+
     /// Calculate the unconstrained execution curve
     /// for the server with priority `index`.
     ///
@@ -261,13 +811,58 @@ impl<'a> System<'a> {
     ) -> OriginalUnconstrainedExecution {
         #![allow(clippy::redundant_closure_for_method_calls)]
 
+        let ahpc = self.original_interference_curve_iter(server_index);
+
+        OriginalUnconstrainedExecution(InverseCurveIterator::new(ahpc))
+    }
+
+    /**
+    Calculate the interference the server with priority `server_index` experiences
+    as assumed by the original algorithm,
+    the aggregated constrained demand of all higher priority servers
+
+    The inverse of this is the original unconstrained execution,
+    see [`System::original_unconstrained_server_execution_curve_iter`]
+    */
+    #[must_use]
+    pub fn original_interference_curve_iter(
+        &self,
+        server_index: usize,
+    ) -> AggregatedHPServerDemand<ConstrainedDemand> {
+        #![allow(clippy::redundant_closure_for_method_calls)]
+
         let csdi = self.servers[..server_index]
             .iter()
             .map(|server| server.constraint_demand_curve_iter());
 
-        let ahpc = System::aggregated_higher_priority_demand_curve_iter(csdi);
+        System::aggregated_higher_priority_demand_curve_iter(csdi)
+    }
 
-        OriginalUnconstrainedExecution(InverseCurveIterator::new(ahpc))
+    /**
+    Calculate the aggregated constrained demand of all servers in the system
+
+    This is the interference a server with a priority lower than all servers in the system
+    would experience as assumed by the original algorithm,
+    see [`System::original_interference_curve_iter`]
+    */
+    #[must_use]
+    pub fn total_constrained_demand_curve_iter(
+        &self,
+    ) -> AggregatedHPServerDemand<ConstrainedDemand> {
+        self.original_interference_curve_iter(self.servers.len())
+    }
+
+    /**
+    Calculate the interference the server with priority `server_index` experiences
+    as assumed by the fixed algorithm,
+    the aggregated actual execution of all higher priority servers
+
+    The inverse of this is the fixed unconstrained execution,
+    see [`System::fixed_unconstrained_server_execution_curve_iter`]
+    */
+    #[must_use]
+    pub fn fixed_interference_curve_iter(&self, server_index: usize) -> AggregatedHPExecution {
+        self.aggregated_higher_priority_actual_execution_curve_iter(server_index)
     }
 
     /**
@@ -278,11 +873,28 @@ impl<'a> System<'a> {
         &self,
         server_index: usize,
     ) -> FixedUnconstrainedExecution {
-        let ahpc = self.aggregated_higher_priority_actual_execution_curve_iter(server_index);
+        let ahpc = self.fixed_interference_curve_iter(server_index);
 
         FixedUnconstrainedExecution(InverseCurveIterator::new(ahpc))
     }
 
+    /**
+    Calculate the execution available to the server with priority `server_index`
+
+    This is the fixed unconstrained execution, see [`System::fixed_unconstrained_server_execution_curve_iter`],
+    i.e. the supply left over by the actual execution of all higher priority servers.
+    The actual execution of the server is calculated by partitioning this curve
+    according to the servers capacity and constraining it by the servers demand.
+    */
+    #[must_use]
+    pub fn available_server_execution_curve_iter(
+        &self,
+        server_index: usize,
+    ) -> ReclassifyIterator<FixedUnconstrainedExecution, AvailableServerExecution> {
+        self.fixed_unconstrained_server_execution_curve_iter(server_index)
+            .reclassify()
+    }
+
     /// Calculate the Constrained Execution Curve using Algorithm 4. from the paper
     /// TODO more detail, what do the parameters mean
     /// # Panics
@@ -301,10 +913,11 @@ impl<'a> System<'a> {
 
         // split unconstrained execution curve into groups every server.interval
         // and check that each group has at least server.capacity of capacity
-        let checked_unconstrained_execution = CapacityCheckIterator::new(
+        let checked_unconstrained_execution = CapacityCheckIterator::new_with_phase(
             unchecked_unconstrained_execution,
-            props.capacity,
+            props.guaranteed_capacity(),
             props.interval,
+            props.phase,
         );
 
         let constrained_demand = self.servers[server_index].constraint_demand_curve_iter();
@@ -316,6 +929,28 @@ impl<'a> System<'a> {
         ))
     }
 
+    /**
+    Calculate the actual execution curves of all servers using the original algorithm,
+    yielding one independent lazy `CurveIterator` per server in priority order
+    */
+    pub fn all_original_actual_execution_curves(
+        &self,
+    ) -> impl Iterator<Item = OriginalActualServerExecution> + '_ {
+        (0..self.servers.len())
+            .map(move |server_index| self.original_actual_execution_curve_iter(server_index))
+    }
+
+    /**
+    Calculate the actual execution curves of all servers using the fixed algorithm,
+    yielding one independent lazy `CurveIterator` per server in priority order
+    */
+    pub fn all_fixed_actual_execution_curves(
+        &self,
+    ) -> impl Iterator<Item = FixedActualExecution> + '_ {
+        (0..self.servers.len())
+            .map(move |server_index| self.fixed_actual_execution_curve_iter(server_index))
+    }
+
     /**
     Calculate the actual execution with the fixed unconstrained server execution rather than the original unconstrained server execution
     */
@@ -328,10 +963,11 @@ impl<'a> System<'a> {
 
         // split unconstrained execution curve into groups every server.interval
         // and check that each group has at least server.capacity of capacity
-        let checked_unconstrained_execution = CapacityCheckIterator::new(
+        let checked_unconstrained_execution = CapacityCheckIterator::new_with_phase(
             unchecked_unconstrained_execution,
-            props.capacity,
+            props.guaranteed_capacity(),
             props.interval,
+            props.phase,
         );
 
         let constrained_demand = self.servers[server_index].constraint_demand_curve_iter();
@@ -342,4 +978,439 @@ impl<'a> System<'a> {
             constrained_demand,
         ))
     }
+
+    /**
+    Find the server with the least slack in `[0, up_to)`
+
+    The slack of a server in one of its replenishment intervals is the part of its capacity
+    that is available but not used by its fixed actual execution,
+    the servers are compared by the minimal slack of all intervals starting before `up_to`.
+    Of servers with the same slack the one with the highest priority is returned.
+
+    Background servers, which have no capacity that could remain unused,
+    and servers that are not guaranteed their capacity every interval are skipped,
+    returns `None` when the system has no servers or all servers were skipped
+    */
+    #[must_use]
+    pub fn critical_server(&self, up_to: TimeUnit) -> Option<usize> {
+        (0..self.servers.len())
+            .filter_map(|server_index| {
+                self.minimal_slack(server_index, up_to)
+                    .map(|slack| (server_index, slack))
+            })
+            .min_by_key(|&(_, slack)| slack)
+            .map(|(server_index, _)| server_index)
+    }
+
+    /**
+    Check that the server with priority `server_index` is guaranteed its capacity
+    in every replenishment interval starting before `up_to`
+
+    This is the assumption from Section 6.1 of the paper, that is otherwise checked lazily
+    by [`System::fixed_actual_execution_curve_iter`] panicking when it does not hold
+    */
+    #[must_use]
+    pub fn budget_guarantee_holds(&self, server_index: usize, up_to: TimeUnit) -> bool {
+        let props = self.servers[server_index].properties;
+
+        // the number of intervals starting before up_to
+        let groups = props.groups_before(up_to);
+        let checked_end = props.group_start(groups);
+
+        let mut checked_unconstrained_execution = TryCapacityCheckIterator::new_with_phase(
+            self.fixed_unconstrained_server_execution_curve_iter(server_index),
+            props.guaranteed_capacity(),
+            props.interval,
+            props.phase,
+        );
+
+        loop {
+            match checked_unconstrained_execution.next_window() {
+                Err(error) => break groups <= error.group,
+                // an interval is checked once a window of a later interval is processed
+                Ok(Some(window)) if window.start < checked_end => {}
+                Ok(_) => break true,
+            }
+        }
+    }
+
+    /**
+    Calculate the minimal slack of the server with priority `server_index`
+    over all its replenishment intervals starting before `up_to`
+
+    Returns `None` for a Background server, which has no capacity that could remain unused,
+    and when the server is not guaranteed its capacity every interval
+    */
+    fn minimal_slack(&self, server_index: usize, up_to: TimeUnit) -> Option<TimeUnit> {
+        let props = self.servers[server_index].properties;
+
+        if matches!(props.server_type, ServerKind::Background) {
+            return None;
+        }
+
+        let groups = props.groups_before(up_to);
+        let mut executed = alloc::vec![TimeUnit::ZERO; groups];
+
+        let mut actual_execution = self.try_actual_execution_curve_iter(server_index);
+
+        while let Some(window) = actual_execution.next_window().ok()? {
+            if up_to <= window.start {
+                break;
+            }
+
+            let (mut remaining, _) = window.split_at(up_to);
+
+            while !remaining.is_empty() {
+                let group = props.budget_group(remaining.start);
+                let (head, tail) = remaining.split_at(props.group_end(group));
+
+                executed[group] += head.length().finite_or(TimeUnit::ZERO);
+
+                remaining = tail;
+            }
+        }
+
+        // the budget-less first group of a phased server has no slack
+        let slack = executed
+            .into_iter()
+            .enumerate()
+            .filter(|&(group, _)| props.group_capacity(group) > TimeUnit::ZERO)
+            .map(|(group, executed)| props.group_capacity(group) - executed)
+            .min()
+            .unwrap_or(props.capacity);
+
+        Some(slack)
+    }
+
+    /**
+    Analyse every Task of every Server summarizing the results in a [`SchedulabilityReport`]
+
+    The WCRT of the Tasks of a Server is calculated using [`Task::try_fixed_worst_case_response_time`]
+    for all jobs arriving before [`System::analysis_end`] of the Server,
+    a Server not being guaranteed its capacity is reported as [`TaskResult::AnalysisFailed`]
+    for the affected Tasks instead of panicking
+
+    Note: As for the individual analysis, this does not terminate when the utilization exceeds one
+    */
+    #[must_use]
+    pub fn schedulability_report(&self) -> SchedulabilityReport {
+        let interval = self
+            .servers
+            .iter()
+            .map(|server| server.properties.interval)
+            .fold(TimeUnit::ONE, TimeUnit::lcm);
+
+        let capacity = self
+            .servers
+            .iter()
+            .map(|server| {
+                (interval / server.properties.interval) * server.properties.guaranteed_capacity()
+            })
+            .sum();
+
+        let servers = (0..self.servers.len())
+            .map(|server_index| {
+                let analysis_end = self.analysis_end(server_index);
+
+                let tasks = self.servers[server_index]
+                    .as_tasks()
+                    .iter()
+                    .enumerate()
+                    .map(|(task_index, task)| {
+                        match Task::try_fixed_worst_case_response_time(
+                            self,
+                            server_index,
+                            task_index,
+                            analysis_end,
+                        ) {
+                            Ok(wcrt) => TaskResult::Analysed {
+                                wcrt,
+                                deadline: task.interval,
+                                schedulable: wcrt <= task.interval,
+                            },
+                            Err(error) => TaskResult::AnalysisFailed(error),
+                        }
+                    })
+                    .collect();
+
+                ServerReport {
+                    analysis_end,
+                    tasks,
+                }
+            })
+            .collect();
+
+        SchedulabilityReport {
+            utilization: Utilization { capacity, interval },
+            servers,
+        }
+    }
+
+    /**
+    Calculate the WCRT of every Task of every Server using [`Task::try_original_worst_case_response_time`],
+    indexed by `[server_index][task_index]`
+
+    For the Tasks of each Server all jobs arriving before `arrival_before(server_index)` are considered,
+    e.g. pass `|server_index| system.system_wide_hyper_period(server_index)`
+
+    A Server not guaranteed its capacity every interval results in a [`CapacityError`]
+    for each of its Tasks, see [`System::schedulability_report`] for a summary of the analysis
+
+    # Panics
+    When sanity checks fail, see [`Task::try_original_worst_case_response_time`]
+    */
+    #[must_use]
+    pub fn response_time_matrix<F>(
+        &self,
+        arrival_before: F,
+    ) -> Vec<Vec<Result<TimeUnit, CapacityError>>>
+    where
+        F: Fn(usize) -> TimeUnit,
+    {
+        (0..self.servers.len())
+            .map(|server_index| {
+                let arrival_before = arrival_before(server_index);
+
+                (0..self.servers[server_index].as_tasks().len())
+                    .map(|task_index| {
+                        Task::try_original_worst_case_response_time(
+                            self,
+                            server_index,
+                            task_index,
+                            arrival_before,
+                        )
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /**
+    Calculate the idle time of the server with priority `server_index` within `[0, up_to)`,
+    the gaps in its fixed actual execution
+
+    The idle time is the spare capacity the server leaves for lower priority work,
+    the last gap is clipped at `up_to` so the returned `CurveIterator` is finite
+
+    # Panics
+    When the server is not guaranteed its capacity, see [`System::fixed_actual_execution_curve_iter`]
+    */
+    #[must_use]
+    pub fn idle_curve_iter(
+        &self,
+        server_index: usize,
+        up_to: TimeUnit,
+    ) -> impl CurveIterator<CurveKind = ServerIdle> + Clone {
+        InverseCurveIterator::<_, ServerIdle>::new(
+            self.fixed_actual_execution_curve_iter(server_index),
+        )
+        .clamp_to(up_to)
+    }
+
+    /**
+    Calculate the busy periods of the server with priority `server_index` within `[0, up_to)`,
+    the maximal intervals of its fixed actual execution without idle time
+
+    Adjacent windows of the actual execution are merged into one busy period,
+    the last busy period is clipped at `up_to`
+
+    # Panics
+    When the server is not guaranteed its capacity, see [`System::fixed_actual_execution_curve_iter`]
+    */
+    #[must_use]
+    pub fn busy_periods(
+        &self,
+        server_index: usize,
+        up_to: TimeUnit,
+    ) -> Vec<Window<<ActualServerExecution as CurveType>::WindowKind>> {
+        let windows = self
+            .fixed_actual_execution_curve_iter(server_index)
+            .into_iterator()
+            .take_while(|window| window.start < up_to)
+            .map(|window| window.split_at(up_to).0)
+            .collect();
+
+        let execution: Curve<ActualServerExecution> = unsafe {
+            // Safety:
+            // a clipped prefix of a CurveIterator is ordered and non-overlapping,
+            // the only violated invariant are adjacent windows which are merged immediately
+            Curve::from_windows_unchecked(windows)
+        }
+        .merge_adjacent();
+
+        execution.into_windows()
+    }
+
+    /**
+    Calculate the length of the longest busy period of the server with priority `server_index`
+    within `[0, up_to)`, see [`System::busy_periods`]
+
+    Returns [`TimeUnit::ZERO`] when the server does not execute before `up_to`
+
+    # Panics
+    When the server is not guaranteed its capacity, see [`System::fixed_actual_execution_curve_iter`]
+    */
+    #[must_use]
+    pub fn longest_busy_period(&self, server_index: usize, up_to: TimeUnit) -> TimeUnit {
+        self.busy_periods(server_index, up_to)
+            .iter()
+            .map(Window::length_or_panic)
+            .max()
+            .unwrap_or(TimeUnit::ZERO)
+    }
+
+    /**
+    Check whether `candidate` can be admitted to the System at the lowest priority,
+    i.e. whether every Task of `candidate` meets its deadline, its interval,
+    for all jobs arriving before `arrival_before`
+
+    The WCRTs are calculated using [`Task::try_fixed_worst_case_response_time`]
+    on a temporary System with `candidate` appended,
+    `candidate` not being guaranteed its capacity is reported as not admittable.
+
+    The Servers already in the System are not re-checked,
+    under fixed priority scheduling they are unaffected by a lower priority Server
+
+    Note: As for the individual analysis, this does not terminate when the utilization exceeds one
+    */
+    #[must_use]
+    pub fn can_admit(&self, candidate: &Server, arrival_before: TimeUnit) -> bool {
+        let servers: Vec<Server> = self
+            .servers
+            .iter()
+            .cloned()
+            .chain(core::iter::once(candidate.clone()))
+            .collect();
+
+        let system = System::new(&servers);
+        let server_index = servers.len() - 1;
+
+        candidate
+            .as_tasks()
+            .iter()
+            .enumerate()
+            .all(|(task_index, task)| {
+                Task::try_fixed_worst_case_response_time(
+                    &system,
+                    server_index,
+                    task_index,
+                    arrival_before,
+                )
+                .map_or(false, |wcrt| wcrt <= task.interval)
+            })
+    }
+
+    /**
+    Check that the actual execution of no two Servers overlaps non-trivially within `[0, up_to)`
+
+    As only one Server can execute at a time this should always hold,
+    the check is performed on the actual execution of the original algorithm
+    as used by [`Task::original_worst_case_response_time`].
+    Only the first overlap found is reported, checking pairs of Servers in priority order.
+
+    # Errors
+    When the actual execution of two Servers overlaps
+
+    # Panics
+    When a server is not guaranteed its capacity every interval,
+    see [`System::budget_guarantee_holds`]
+    */
+    pub fn check_no_execution_overlap(&self, up_to: TimeUnit) -> Result<(), OverlapReport> {
+        // clamp the executions so that windows straddling up_to are clipped
+        let executions: Vec<Curve<_>> = (0..self.servers.len())
+            .map(|server_index| {
+                self.original_actual_execution_curve_iter(server_index)
+                    .clamp_to(up_to)
+                    .collect_curve()
+            })
+            .collect();
+
+        for (higher_priority_server, higher) in executions.iter().enumerate() {
+            for (lower_priority_server, lower) in executions
+                .iter()
+                .enumerate()
+                .skip(higher_priority_server + 1)
+            {
+                let overlap =
+                    first_window_overlap(higher.iter_windows(), lower.iter_windows(), up_to);
+
+                if let Some((higher_priority_window, lower_priority_window)) = overlap {
+                    return Err(OverlapReport {
+                        higher_priority_server,
+                        lower_priority_server,
+                        higher_priority_window,
+                        lower_priority_window,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+    Calculate the actual execution like [`System::fixed_actual_execution_curve_iter`],
+    but report the server not being guaranteed its capacity every interval
+    as a [`CapacityError`] instead of panicking
+    */
+    #[must_use]
+    pub fn try_actual_execution_curve_iter(&self, server_index: usize) -> TryActualExecution {
+        self.try_actual_execution_impl(
+            server_index,
+            self.fixed_unconstrained_server_execution_curve_iter(server_index),
+        )
+    }
+
+    /**
+    Calculate the actual execution like [`System::original_actual_execution_curve_iter`],
+    but report the server not being guaranteed its capacity every interval
+    as a [`CapacityError`] instead of panicking
+    */
+    #[must_use]
+    pub fn try_original_actual_execution_curve_iter(
+        &self,
+        server_index: usize,
+    ) -> TryActualExecution<OriginalUnconstrainedExecution> {
+        self.try_actual_execution_impl(
+            server_index,
+            self.original_unconstrained_server_execution_curve_iter(server_index),
+        )
+    }
+
+    /// Calculate the actual execution of the server with priority `server_index`
+    /// from its `unchecked_unconstrained_execution`, recording a lack of capacity
+    fn try_actual_execution_impl<UE>(
+        &self,
+        server_index: usize,
+        unchecked_unconstrained_execution: UE,
+    ) -> TryActualExecution<UE>
+    where
+        UE: CurveIterator<CurveKind = UnconstrainedServerExecution>,
+    {
+        let props = self.servers[server_index].properties;
+
+        let error = Rc::new(Cell::new(None));
+
+        let checked_unconstrained_execution = CapacityErrorRecorder {
+            iter: TryCapacityCheckIterator::new_with_phase(
+                unchecked_unconstrained_execution,
+                props.guaranteed_capacity(),
+                props.interval,
+                props.phase,
+            ),
+            error: Rc::clone(&error),
+            last_end: TimeUnit::ZERO,
+        };
+
+        let constrained_demand = self.servers[server_index].constraint_demand_curve_iter();
+
+        TryActualExecution {
+            iter: ActualServerExecutionIterator::new(
+                props,
+                checked_unconstrained_execution,
+                constrained_demand,
+            ),
+            error,
+        }
+    }
 }
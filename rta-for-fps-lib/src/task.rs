@@ -1,21 +1,27 @@
 //! Module for the Task definition
 
-use crate::curve::curve_types::CurveType;
+use crate::curve::curve_types::{CurveType, UnspecifiedCurve};
 use crate::curve::{AggregateExt, Curve};
 use crate::iterators::curve::{
-    AggregationIterator, CurveDeltaIterator, OverlapIterator, RemainingSupplyIterator,
+    AggregationIterator, CapacityError, CurveDeltaIterator, Delta, OverlapIterator,
+    RemainingSupplyIterator,
 };
 use crate::iterators::task::TaskDemandIterator;
-use crate::iterators::{CurveIterator, ReclassifyIterator};
-use crate::server::ActualServerExecution;
-use crate::system::{FixedActualExecution, OriginalActualServerExecution, System};
+use crate::iterators::{CurveIterator, ReclassifyIterator, ReinterpretIterator};
+use crate::server::{ActualServerExecution, ServerKind, UnconstrainedServerExecution};
+use crate::system::{
+    FixedActualExecution, OriginalActualServerExecution, System, TryActualExecution,
+    UntilCapacityError,
+};
 use crate::task::curve_types::{
     ActualTaskExecution, AvailableTaskExecution, HigherPriorityTaskDemand,
 };
 use crate::time::{TimeUnit, UnitNumber};
 use crate::window::WindowEnd;
-use crate::window::{Demand, Window};
-use core::fmt::Debug;
+use crate::window::{Demand, Supply, Window};
+use alloc::rc::Rc;
+use core::convert::TryFrom;
+use core::fmt::{Debug, Display, Formatter};
 
 pub mod curve_types {
     //! Module for `CurveType`s of a Task
@@ -48,6 +54,54 @@ pub struct Task {
     pub demand: TimeUnit,
     /// The interval of the task, called Period P index i in the paper
     pub interval: TimeUnit,
+    /// How long before the analysis origin the task began,
+    /// shifting all job arrivals earlier, see [`Task::with_phase`]
+    ///
+    /// The whole demand of a job arriving before the origin is placed at the origin
+    /// and its response time is measured from its arrival before the origin
+    pub pre_start: TimeUnit,
+    /// The release jitter of the task, see [`Task::with_jitter`]
+    ///
+    /// Each job may be released up to `jitter` after its nominal arrival,
+    /// the demand curve assumes the critical case of the first job being released as late as possible
+    /// and all later jobs at their nominal arrival
+    pub jitter: TimeUnit,
+}
+
+/// Error for Task parameters not describing a valid Task
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskError {
+    /// The interval of the Task is shorter than its demand
+    IntervalShorterThanDemand {
+        /// the demand of the Task
+        demand: TimeUnit,
+        /// the interval of the Task
+        interval: TimeUnit,
+    },
+    /// The utilization of the Task is not in `(0, 1]`
+    UtilizationOutOfRange,
+    /// The interval of a Task created from its utilization is zero
+    ZeroInterval,
+}
+
+impl Display for TaskError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TaskError::IntervalShorterThanDemand { demand, interval } => write!(
+                f,
+                "Task can't have an interval ({interval:?}) shorter than its demand ({demand:?})!"
+            ),
+            TaskError::UtilizationOutOfRange => {
+                write!(f, "Task can't have a utilization outside of (0, 1]!")
+            }
+            TaskError::ZeroInterval => {
+                write!(
+                    f,
+                    "Task created from its utilization can't have a zero interval!"
+                )
+            }
+        }
+    }
 }
 
 /**
@@ -151,14 +205,173 @@ impl Task {
         let demand = demand.into();
         let interval = interval.into();
 
+        assert!(
+            interval >= demand,
+            "Task can't have an interval shorter than its demand!"
+        );
+
+        Task {
+            offset: offset.into(),
+            demand,
+            interval,
+            pre_start: TimeUnit::ZERO,
+            jitter: TimeUnit::ZERO,
+        }
+    }
+
+    /// Create a new Task with the corresponding parameters,
+    /// the fallible counterpart of [`Task::new`]
+    ///
+    /// # Errors
+    /// If the interval is shorter than the demand
+    pub fn try_new<I: Into<TimeUnit>>(
+        demand: I,
+        interval: I,
+        offset: I,
+    ) -> Result<Self, TaskError> {
+        let demand = demand.into();
+        let interval = interval.into();
+
         if interval < demand {
-            panic!("Task can't have an interval shorter than its demand!")
+            return Err(TaskError::IntervalShorterThanDemand { demand, interval });
         }
 
-        Task {
+        Ok(Task {
             offset: offset.into(),
             demand,
             interval,
+            pre_start: TimeUnit::ZERO,
+            jitter: TimeUnit::ZERO,
+        })
+    }
+
+    /// Create a new Task from its utilization instead of its demand
+    ///
+    /// The demand is `floor(utilization * interval)` clamped to at least 1 and at most the interval,
+    /// such that low utilizations still produce some demand
+    ///
+    /// # Panics
+    /// If the utilization is not in `(0, 1]` or the interval is zero
+    #[must_use]
+    pub fn from_utilization(utilization: f64, interval: TimeUnit, offset: TimeUnit) -> Self {
+        Task::try_from_utilization(utilization, interval, offset)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Create a new Task from its utilization instead of its demand,
+    /// the fallible counterpart of [`Task::from_utilization`]
+    ///
+    /// # Errors
+    /// If the utilization is not in `(0, 1]` or the interval is zero
+    pub fn try_from_utilization(
+        utilization: f64,
+        interval: TimeUnit,
+        offset: TimeUnit,
+    ) -> Result<Self, TaskError> {
+        // also rejects NaN
+        if !(0.0 < utilization && utilization <= 1.0) {
+            return Err(TaskError::UtilizationOutOfRange);
+        }
+
+        if interval == TimeUnit::ZERO {
+            return Err(TaskError::ZeroInterval);
+        }
+
+        // the product is non-negative and at most the interval,
+        // so truncating is flooring and can't overflow
+        #[allow(
+            clippy::cast_precision_loss,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss
+        )]
+        let demand = (utilization * interval.as_unit() as f64) as UnitNumber;
+        let demand = TimeUnit::from(demand.max(1)).min(interval);
+
+        Task::try_new(demand, interval, offset)
+    }
+
+    /// Create a new Task whose first job arrives at `phase`,
+    /// which may be negative for a task that began before the analysis origin
+    ///
+    /// Jobs arriving an interval or more before the origin are assumed to have completed
+    /// by their implicit deadline and are therefore dropped, such that the first job arrives in `(-interval, ∞)`.
+    /// A job arriving before the origin is assumed to not have been served before the origin,
+    /// so its whole demand is placed at the origin,
+    /// while its response time is still measured from its arrival before the origin.
+    ///
+    /// # Panics
+    /// If the interval is shorter than the demand or zero
+    #[must_use]
+    pub fn with_phase<I: Into<TimeUnit>>(demand: I, interval: I, phase: i64) -> Self {
+        let mut task = Task::new(demand.into(), interval.into(), TimeUnit::ZERO);
+
+        assert!(
+            task.interval > TimeUnit::ZERO,
+            "Task can't have an interval of zero!"
+        );
+
+        let magnitude = UnitNumber::try_from(phase.unsigned_abs()).expect("Phase too large");
+
+        if phase < 0 {
+            task.pre_start = TimeUnit::from(magnitude % task.interval.as_unit());
+        } else {
+            task.offset = TimeUnit::from(magnitude);
+        }
+
+        task
+    }
+
+    /// Set the release jitter of the Task
+    ///
+    /// Each job is released up to `jitter` after its nominal arrival.
+    /// The demand curve models the critical case of the first job being released `jitter` late
+    /// while all later jobs are released at their nominal arrival,
+    /// compressing the demand of the first jobs. The demand of a job released
+    /// before the previous job's demand is served is queued behind it.
+    /// The response time of a job is measured from its nominal arrival, see [`Task::job_arrival`]
+    #[must_use]
+    pub const fn with_jitter(mut self, jitter: TimeUnit) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Create a copy of the Task with its demand replaced by `demand`
+    ///
+    /// # Panics
+    /// If the interval is shorter than the new demand
+    #[must_use]
+    pub fn with_demand(self, demand: TimeUnit) -> Self {
+        self.with_parameters(demand, self.interval, self.offset)
+    }
+
+    /// Create a copy of the Task with its interval replaced by `interval`
+    ///
+    /// # Panics
+    /// If the new interval is shorter than the demand
+    #[must_use]
+    pub fn with_interval(self, interval: TimeUnit) -> Self {
+        self.with_parameters(self.demand, interval, self.offset)
+    }
+
+    /// Create a copy of the Task with its offset replaced by `offset`
+    #[must_use]
+    pub fn with_offset(self, offset: TimeUnit) -> Self {
+        self.with_parameters(self.demand, self.interval, offset)
+    }
+
+    /// Create a copy of the Task with its demand, interval and offset replaced,
+    /// keeping the remaining properties such as the jitter
+    ///
+    /// # Panics
+    /// If the interval is shorter than the demand
+    fn with_parameters(self, demand: TimeUnit, interval: TimeUnit, offset: TimeUnit) -> Self {
+        let task =
+            Task::try_new(demand, interval, offset).unwrap_or_else(|error| panic!("{}", error));
+
+        Task {
+            pre_start: self.pre_start,
+            jitter: self.jitter,
+            ..task
         }
     }
 
@@ -274,114 +487,302 @@ impl Task {
         task_index: usize,
         arrival_before: TimeUnit,
     ) -> TimeUnit {
-        let swh = arrival_before;
-
         let actual_execution_time_iter =
             Task::original_actual_execution_curve_iter(system, server_index, task_index);
 
         let task = &system.as_servers()[server_index].as_tasks()[task_index];
 
-        // arrival of the last job that starts before the swh
-        let last_job = (swh - task.offset - TimeUnit::ONE) / task.interval;
+        task.worst_case_response_time_impl(actual_execution_time_iter, arrival_before)
+    }
+
+    /// Same as `original_worst_case_response_time` in most aspects, but uses the `fixed_actual_execution_curve_iter` instead of the `original_actual_execution_curve_iter`
+    /// # Panics
+    /// When sanity checks fail
+    #[must_use]
+    pub fn fixed_worst_case_response_time(
+        system: &System,
+        server_index: usize,
+        task_index: usize,
+        arrival_before: TimeUnit,
+    ) -> TimeUnit {
+        let actual_execution_time_iter =
+            Task::fixed_actual_execution_curve_iter(system, server_index, task_index);
+
+        let task = &system.as_servers()[server_index].as_tasks()[task_index];
+
+        task.worst_case_response_time_impl(actual_execution_time_iter, arrival_before)
+    }
+
+    /// Same as `fixed_worst_case_response_time`, but uses [`System::try_actual_execution_curve_iter`]
+    /// reporting the server not being guaranteed its capacity as an error instead of panicking
+    ///
+    /// # Errors
+    /// When the server was not guaranteed its capacity in a group processed by the analysis
+    ///
+    /// # Panics
+    /// When sanity checks fail
+    pub fn try_fixed_worst_case_response_time(
+        system: &System,
+        server_index: usize,
+        task_index: usize,
+        arrival_before: TimeUnit,
+    ) -> Result<TimeUnit, CapacityError> {
+        Task::try_worst_case_response_time_impl(
+            system,
+            server_index,
+            task_index,
+            arrival_before,
+            system.try_actual_execution_curve_iter(server_index),
+        )
+    }
+
+    /// Same as `original_worst_case_response_time`, but uses [`System::try_original_actual_execution_curve_iter`]
+    /// reporting the server not being guaranteed its capacity as an error instead of panicking
+    ///
+    /// # Errors
+    /// When the server was not guaranteed its capacity in a group processed by the analysis
+    ///
+    /// # Panics
+    /// When sanity checks fail
+    pub fn try_original_worst_case_response_time(
+        system: &System,
+        server_index: usize,
+        task_index: usize,
+        arrival_before: TimeUnit,
+    ) -> Result<TimeUnit, CapacityError> {
+        Task::try_worst_case_response_time_impl(
+            system,
+            server_index,
+            task_index,
+            arrival_before,
+            system.try_original_actual_execution_curve_iter(server_index),
+        )
+    }
+
+    /// Calculate the WCRT of a Task from the fallible actual execution `server_execution` of its Server,
+    /// stopping at the first [`CapacityError`]
+    fn try_worst_case_response_time_impl<UE>(
+        system: &System,
+        server_index: usize,
+        task_index: usize,
+        arrival_before: TimeUnit,
+        server_execution: TryActualExecution<UE>,
+    ) -> Result<TimeUnit, CapacityError>
+    where
+        UE: CurveIterator<CurveKind = UnconstrainedServerExecution> + Clone,
+    {
+        let error = Rc::clone(&server_execution.error);
+        let asec = UntilCapacityError(server_execution);
+
+        let tasks = system.as_servers()[server_index].as_tasks();
+        let hptd = Task::higher_priority_task_demand_iter(tasks, task_index);
 
-        let total_execution = (last_job + 1) * task.demand;
-        let mut provided = WindowEnd::Finite(TimeUnit::ZERO);
+        let available_execution_curve = Task::available_execution_curve_impl(asec, hptd);
+
+        let task = &tasks[task_index];
+
+        let actual_execution_time_iter =
+            CurveDeltaIterator::new(available_execution_curve, task.into_iter())
+                .overlap::<ActualTaskExecution>();
 
         let actual_execution_time: Curve<_> = actual_execution_time_iter
-            .take_while_curve(|task| {
-                let take = provided < total_execution;
-                provided += task.length();
-                take
-            })
+            .take_up_to_capacity(task.jobs_arriving_before(arrival_before) * task.demand)
             .collect_curve();
 
-        // sanity check that last_job arrival is before swh
-        assert!(
-            task.job_arrival(last_job) < swh,
-            "Last job should arrive before the system wide hyper period"
-        );
+        // the execution ends at the error, lacking the execution the sanity checks expect
+        if let Some(error) = error.get() {
+            return Err(error);
+        }
 
-        // sanity check that job after last_job is not before swh
-        assert!(
-            swh <= task.job_arrival(last_job + 1),
-            "The job after the last job would arrive after or at the system wide hyper period"
-        );
+        Ok(Task::worst_case_response_time_from_curve(
+            &actual_execution_time,
+            task,
+            arrival_before,
+        ))
+    }
 
-        assert!(
-            WindowEnd::Finite((last_job + 1) * task.demand) <= actual_execution_time.capacity(),
-            "There should be enough capacity for the last job"
-        );
+    /// Count how often the jobs of the Task with priority `task_index` of the Server with priority `server_index`
+    /// are preempted within `[0, up_to]`
+    ///
+    /// A preemption is a gap in the fixed actual execution of the Task while a job has remaining demand,
+    /// i.e. the number of contiguous execution segments of each job minus one, summed over all jobs.
+    /// Adjacent execution windows are treated as contiguous and thus not as a preemption.
+    ///
+    /// # Panics
+    /// When the server is not guaranteed its capacity, see [`System::fixed_actual_execution_curve_iter`]
+    #[must_use]
+    pub fn preemption_count(
+        system: &System,
+        server_index: usize,
+        task_index: usize,
+        up_to: TimeUnit,
+    ) -> UnitNumber {
+        let task = &system.as_servers()[server_index].as_tasks()[task_index];
 
-        (0..=last_job)
-            .into_iter()
-            .map(|job| {
-                let arrival = task.job_arrival(job);
-                let t = (job + 1) * task.demand;
+        let demand = |jobs: UnitNumber| jobs * task.demand;
 
-                Task::time_to_provide(&actual_execution_time, t) - arrival
-            })
-            .max()
-            .unwrap_or(TimeUnit::ZERO)
+        let mut execution =
+            Task::fixed_actual_execution_curve_iter(system, server_index, task_index).normalize();
+
+        let mut executed = TimeUnit::ZERO;
+        // the number of jobs completed by the end of the previous window
+        let mut completed = 0;
+        let mut preemptions = 0;
+
+        while let Some(window) = execution.next_window() {
+            if up_to <= window.start {
+                break;
+            }
+
+            // as the windows are normalized there is a gap before every window but the first
+            if executed > TimeUnit::ZERO {
+                while demand(completed + 1) <= executed {
+                    completed += 1;
+                }
+
+                // the gap doesn't fall between two jobs
+                if demand(completed) != executed {
+                    preemptions += 1;
+                }
+            }
+
+            let (head, _) = window.split_at(up_to);
+            executed += head.length_or_panic();
+        }
+
+        preemptions
     }
 
-    /// Same as `original_worst_case_response_time` in most aspects, but uses the `fixed_actual_execution_curve_iter` instead of the `original_actual_execution_curve_iter`
+    /// Calculate the largest demand the task with priority `task_index` of the server with priority `server_index`
+    /// can have, while its WCRT does not exceed its interval, keeping all other parameters fixed
+    ///
+    /// Performs a binary search over the demand in the range `1..=interval`,
+    /// calculating the WCRT using [`Task::original_worst_case_response_time`] for each probe
+    ///
+    /// A demand for which the tasks of the server demand more than the server's capacity
+    /// over a hyper period is treated as infeasible without calculating the WCRT,
+    /// as the demand would build up over time. This also avoids probing
+    /// task sets with a utilization above one, for which the aggregated demand never ends.
+    /// A Background Server is not limited by its capacity, so for it only the latter is checked.
+    ///
+    /// Returns [`TimeUnit::ZERO`] if even a demand of [`TimeUnit::ONE`] is infeasible
+    ///
     /// # Panics
-    /// When sanity checks fail
+    /// When sanity checks fail, see [`Task::original_worst_case_response_time`]
     #[must_use]
-    pub fn fixed_worst_case_response_time(
+    pub fn max_demand_for_feasibility(
         system: &System,
         server_index: usize,
         task_index: usize,
         arrival_before: TimeUnit,
     ) -> TimeUnit {
-        let swh = arrival_before;
+        let servers = system.as_servers();
+        let task = servers[server_index].as_tasks()[task_index];
 
-        let actual_execution_time_iter =
-            Task::fixed_actual_execution_curve_iter(system, server_index, task_index);
+        let feasible = |demand: UnitNumber| {
+            let mut tasks = servers[server_index].as_tasks().to_vec();
+            tasks[task_index] = tasks[task_index].with_demand(TimeUnit::from(demand));
 
-        let task = &system.as_servers()[server_index].as_tasks()[task_index];
+            let properties = servers[server_index].properties;
+            let hyper_period = Task::set_hyperperiod(&tasks).lcm(properties.interval);
+            let total_demand: TimeUnit = tasks
+                .iter()
+                .map(|task| (hyper_period / task.interval) * task.demand)
+                .sum();
 
-        // arrival of the last job that starts before the swh
-        let last_job = (swh - task.offset - TimeUnit::ONE) / task.interval;
+            let available = if matches!(properties.server_type, ServerKind::Background) {
+                hyper_period
+            } else {
+                (hyper_period / properties.interval) * properties.capacity
+            };
 
-        let total_execution = (last_job + 1) * task.demand;
-        let mut provided = WindowEnd::Finite(TimeUnit::ZERO);
+            if available < total_demand {
+                return false;
+            }
 
-        let actual_execution_time: Curve<_> = actual_execution_time_iter
-            .take_while_curve(|task| {
-                let take = provided < total_execution;
-                provided += task.length();
-                take
-            })
-            .collect_curve();
+            let mut modified_servers = servers.to_vec();
+            modified_servers[server_index] = servers[server_index].with_tasks(&tasks);
 
-        // sanity check that last_job arrival is before swh
-        assert!(
-            task.job_arrival(last_job) < swh,
-            "Last job should arrive before the system wide hyper period"
-        );
+            let modified_system = System::new(&modified_servers);
 
-        // sanity check that job after last_job is not before swh
-        assert!(
-            swh <= task.job_arrival(last_job + 1),
-            "The job after the last job would arrive after or at the system wide hyper period"
-        );
+            Task::original_worst_case_response_time(
+                &modified_system,
+                server_index,
+                task_index,
+                arrival_before,
+            ) <= task.interval
+        };
 
-        assert!(
-            WindowEnd::Finite((last_job + 1) * task.demand) <= actual_execution_time.capacity(),
-            "There should be enough capacity for the last job"
-        );
+        // invariant: `low` is feasible or 0, everything above `high` is infeasible
+        let mut low = 0;
+        let mut high = task.interval.as_unit();
 
-        (0..=last_job)
-            .into_iter()
-            .map(|job| {
-                let arrival = task.job_arrival(job);
-                let t = (job + 1) * task.demand;
+        while low < high {
+            // rounding up so that `low = mid` always makes progress
+            let mid = high - (high - low) / 2;
+            if feasible(mid) {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
 
-                Task::time_to_provide(&actual_execution_time, t) - arrival
-            })
-            .max()
-            .unwrap_or(TimeUnit::ZERO)
+        TimeUnit::from(low)
+    }
+
+    /// Calculate the length of the level-i busy period of the task with priority `task_index`
+    /// of the server with priority `server_index`
+    ///
+    /// The busy period starts with the first arrival of the task or a higher priority task of the server
+    /// and lasts until all of their demand that arrived before has been served,
+    /// using the fixed actual execution of the server to serve the demand
+    ///
+    /// # Panics
+    /// When the server is not guaranteed its capacity every interval
+    ///
+    /// # Warning
+    /// Won't terminate when the demand of the tasks exceeds what the server can serve in the long run
+    #[must_use]
+    pub fn busy_period(system: &System, server_index: usize, task_index: usize) -> TimeUnit {
+        let tasks = &system.as_servers()[server_index].as_tasks()[..=task_index];
+
+        let level_demand = Task::higher_priority_task_demand_iter(tasks, tasks.len());
+
+        // the busy period starts with the first demand, with jitter not necessarily the first arrival
+        let start = level_demand
+            .clone()
+            .next_window()
+            .map_or(TimeUnit::ZERO, |window| window.start);
+
+        // the demand of all tasks arriving before `time`
+        let released = |time: TimeUnit| -> TimeUnit {
+            tasks
+                .iter()
+                .map(|task| task.released_demand_before(time))
+                .sum()
+        };
+
+        let server_execution = system.fixed_actual_execution_curve_iter(server_index);
+
+        let mut executed = TimeUnit::ZERO;
+
+        for execution in
+            CurveDeltaIterator::new(server_execution, level_demand).filter_map(Delta::overlap)
+        {
+            match execution.end {
+                WindowEnd::Finite(end) => {
+                    executed += end - execution.start;
+                    if released(end) <= executed {
+                        return end - start;
+                    }
+                }
+                WindowEnd::Infinite => {
+                    unreachable!("The demand is made up of finite windows so the overlap is finite")
+                }
+            }
+        }
+
+        unreachable!("The server execution is infinite, so the delta won't run out of overlap before the busy period ends")
     }
 
     /// Calculate the time till the execution curve has served t Units of Demand
@@ -408,13 +809,9 @@ impl Task {
             .iter()
             .enumerate()
             .scan(TimeUnit::ZERO, |acc, (index, window)| {
-                match window.length() {
-                    WindowEnd::Finite(length) => {
-                        *acc += length;
-                        (*acc < t).then(|| (index + 1, *acc))
-                    }
-                    WindowEnd::Infinite => None,
-                }
+                let length = window.length_finite()?;
+                *acc += length;
+                (*acc < t).then(|| (index + 1, *acc))
             })
             .last()
             .unwrap_or((0, TimeUnit::ZERO));
@@ -425,19 +822,262 @@ impl Task {
         // this should hold as sum is the largest sum of head window lengths less than t
         debug_assert!(
             b > TimeUnit::ZERO,
-            "There should be remaining demand, but b = {:?}",
+            "There should be remaining demand, but b = {}",
             b
         );
 
         actual_execution_time.as_windows()[index].start + b
     }
 
-    /// Calculate the arrival for the job_index+1-th job
+    /// Scale the time of the Task by `factor`,
+    /// multiplying its offset, demand, interval and jitter
+    ///
+    /// # Panics
+    /// When `factor` is 0, as the Task would have a zero interval
+    #[must_use]
+    pub fn scale_time(self, factor: UnitNumber) -> Self {
+        assert!(factor > 0, "Can't scale the time of a Task by 0!");
+
+        Task {
+            offset: self.offset * factor,
+            demand: self.demand * factor,
+            interval: self.interval * factor,
+            pre_start: self.pre_start * factor,
+            jitter: self.jitter * factor,
+        }
+    }
+
+    /// Calculate the hyperperiod of this Task and `other`,
+    /// the least common multiple of their intervals
+    #[must_use]
+    pub const fn hyperperiod_with(&self, other: &Task) -> TimeUnit {
+        self.interval.lcm(other.interval)
+    }
+
+    /// Calculate the hyperperiod of a set of Tasks,
+    /// the least common multiple of their intervals
+    ///
+    /// Unlike [`System::system_wide_hyper_period`] this ignores server intervals,
+    /// for an empty set of Tasks the hyperperiod is [`TimeUnit::ONE`]
+    #[must_use]
+    pub fn set_hyperperiod(tasks: &[Task]) -> TimeUnit {
+        tasks
+            .iter()
+            .map(|task| task.interval)
+            .fold(TimeUnit::ONE, TimeUnit::lcm)
+    }
+
+    /// Calculate the nominal arrival for the job_index+1-th job
+    ///
+    /// With jitter the job may be released after this, see [`Task::with_jitter`].
+    /// Jobs arriving before the analysis origin are clamped to arrive at the origin,
+    /// jobs arriving an interval or more before the origin are not counted, see [`Task::with_phase`]
     ///
     /// Note: The paper uses 1-index for jobs while this uses 0-index
+    ///
+    /// # Panics
+    /// When the arrival overflows
     #[must_use]
     pub fn job_arrival(&self, job_index: UnitNumber) -> TimeUnit {
-        self.offset + job_index * self.interval
+        self.shifted_arrival(job_index)
+            .saturating_sub(self.pre_start)
+    }
+
+    /// Calculate the release of the job_index+1-th job,
+    /// the first job is released `jitter` after its nominal arrival
+    /// while later jobs are released at their nominal arrival
+    ///
+    /// Jobs released before the analysis origin are clamped to be released at the origin,
+    /// returning `None` on overflow
+    pub(crate) fn checked_job_release(&self, job_index: UnitNumber) -> Option<TimeUnit> {
+        let shifted_arrival = self.checked_shifted_arrival(job_index)?;
+
+        let shifted_release = if job_index == 0 {
+            shifted_arrival.checked_add(self.jitter)?
+        } else {
+            shifted_arrival
+        };
+
+        Some(shifted_release.saturating_sub(self.pre_start))
+    }
+
+    /// The number of jobs that arrived an interval or more before the analysis origin,
+    /// these are assumed to have completed before the origin and are not part of the demand
+    fn dropped_jobs(&self) -> UnitNumber {
+        if self.pre_start <= self.offset {
+            0
+        } else {
+            (self.pre_start - self.offset) / self.interval
+        }
+    }
+
+    /// Calculate the arrival of the job_index+1-th job relative to the start of the Task
+    /// rather than the analysis origin, i.e. shifted later by `pre_start`,
+    /// returning `None` on overflow
+    fn checked_shifted_arrival(&self, job_index: UnitNumber) -> Option<TimeUnit> {
+        let job = self.dropped_jobs().checked_add(job_index)?;
+        self.offset.checked_add(self.interval.checked_mul(job)?)
+    }
+
+    /// Calculate the arrival of the job_index+1-th job relative to the start of the Task
+    ///
+    /// # Panics
+    /// When the arrival overflows
+    fn shifted_arrival(&self, job_index: UnitNumber) -> TimeUnit {
+        self.checked_shifted_arrival(job_index)
+            .unwrap_or_else(|| panic!("The arrival of job {} overflows", job_index))
+    }
+
+    /// Calculate how long before the analysis origin the job_index+1-th job arrived,
+    /// [`TimeUnit::ZERO`] for jobs arriving at or after the origin
+    ///
+    /// The response time of such a job is measured from its arrival before the origin
+    fn carry_in(&self, job_index: UnitNumber) -> TimeUnit {
+        self.pre_start
+            .saturating_sub(self.shifted_arrival(job_index))
+    }
+
+    /// Calculate the demand of all jobs of the Task that are released before `t`,
+    /// including the whole demand of jobs that arrived before the analysis origin
+    ///
+    /// # Panics
+    /// When the release of the first job overflows
+    #[must_use]
+    pub fn released_demand_before(&self, t: TimeUnit) -> TimeUnit {
+        let arrived = self.jobs_arriving_before(t);
+
+        // only the first job is released after its nominal arrival
+        let first_delayed = arrived > 0
+            && self
+                .checked_job_release(0)
+                .map_or(true, |release| t <= release);
+
+        let released = if first_delayed { arrived - 1 } else { arrived };
+
+        released * self.demand
+    }
+
+    /// Calculate the demand of the jobs of the Task that arrive within `[start, end)`
+    ///
+    /// Jobs that arrived before the analysis origin count as arriving at the origin
+    #[must_use]
+    pub fn demand_in_window(&self, start: TimeUnit, end: TimeUnit) -> TimeUnit {
+        if end <= start {
+            return TimeUnit::ZERO;
+        }
+
+        let jobs = self.jobs_arriving_before(end) - self.jobs_arriving_before(start);
+
+        jobs * self.demand
+    }
+
+    /// Count the jobs of the Task that arrive before `t`,
+    /// jobs that arrived before the analysis origin count as arriving at the origin
+    fn jobs_arriving_before(&self, t: TimeUnit) -> UnitNumber {
+        if t == TimeUnit::ZERO {
+            return 0;
+        }
+
+        let t = t + self.pre_start;
+
+        if t <= self.offset {
+            0
+        } else {
+            let elapsed = (t - self.offset).as_unit();
+            let interval = self.interval.as_unit();
+
+            // ceil(elapsed / interval) without overflowing
+            elapsed / interval + UnitNumber::from(elapsed % interval != 0) - self.dropped_jobs()
+        }
+    }
+
+    /// Generate the Demand Curve for the Task
+    /// stopping before the first window ending after `limit`
+    #[must_use]
+    pub const fn bounded_demand_iter(self, limit: TimeUnit) -> TaskDemandIterator {
+        TaskDemandIterator::new_bounded(self, limit)
+    }
+
+    /// Generate a Supply Curve with the same periodic structure as the Tasks Demand
+    ///
+    /// Useful for modeling a task that provides rather than requires service,
+    /// e.g. as the supply of a [`CurveDeltaIterator`]
+    #[must_use]
+    pub fn supply_curve_iter(&self) -> impl CurveIterator<CurveKind = UnspecifiedCurve<Supply>> {
+        ReinterpretIterator::new(TaskDemandIterator::new(*self))
+    }
+
+    /// Calculate the WCRT from the Tasks actual execution
+    /// for all jobs arriving before `arrival_before`
+    ///
+    /// # Panics
+    /// When sanity checks fail
+    fn worst_case_response_time_impl<AE>(
+        &self,
+        actual_execution_time_iter: AE,
+        arrival_before: TimeUnit,
+    ) -> TimeUnit
+    where
+        AE: CurveIterator<CurveKind = ActualTaskExecution>,
+    {
+        let actual_execution_time: Curve<_> = actual_execution_time_iter
+            .take_up_to_capacity(self.jobs_arriving_before(arrival_before) * self.demand)
+            .collect_curve();
+
+        Task::worst_case_response_time_from_curve(&actual_execution_time, self, arrival_before)
+    }
+
+    /// Calculate the WCRT of `task` for all jobs arriving before `arrival_before`
+    /// from an already collected actual execution of the Task,
+    /// e.g. from [`Task::fixed_actual_execution_curve_iter`]
+    ///
+    /// Avoids recalculating the actual execution when it is needed for more than the WCRT,
+    /// the curve may extend past the execution of the last job arriving before `arrival_before`
+    ///
+    /// # Panics
+    /// When sanity checks fail,
+    /// e.g. `actual_execution` does not contain the execution of all jobs arriving before `arrival_before`
+    #[must_use]
+    pub fn worst_case_response_time_from_curve(
+        actual_execution: &Curve<ActualTaskExecution>,
+        task: &Task,
+        arrival_before: TimeUnit,
+    ) -> TimeUnit {
+        let swh = arrival_before;
+
+        // the number of jobs that arrive before the swh
+        let jobs = task.jobs_arriving_before(swh);
+
+        let total_execution = jobs * task.demand;
+
+        // sanity check that the last job arrives before swh
+        assert!(
+            jobs == 0 || task.job_arrival(jobs - 1) < swh,
+            "Last job should arrive before the system wide hyper period"
+        );
+
+        // sanity check that job after the last job is not before swh
+        assert!(
+            swh <= task.job_arrival(jobs),
+            "The job after the last job would arrive after or at the system wide hyper period"
+        );
+
+        assert!(
+            WindowEnd::Finite(total_execution) <= actual_execution.capacity(),
+            "There should be enough capacity for the last job"
+        );
+
+        (0..jobs)
+            .map(|job| {
+                let arrival = task.job_arrival(job);
+                let t = (job + 1) * task.demand;
+
+                // jobs arriving before the origin are clamped to arrive at the origin,
+                // so add the time they arrived before it
+                Task::time_to_provide(actual_execution, t) - arrival + task.carry_in(job)
+            })
+            .max()
+            .unwrap_or(TimeUnit::ZERO)
     }
 }
 
@@ -1,18 +1,24 @@
 //! Module defining a Unit of Time
 
-use core::fmt::{Debug, Formatter};
+use core::convert::TryFrom;
+use core::fmt::{Debug, Display, Formatter};
 use core::iter::Sum;
+use core::num::ParseIntError;
 use core::ops::{Add, AddAssign, Div, Mul, Sub};
+use core::str::FromStr;
+use core::time::Duration;
 
 mod util {
     //! Utility Module for the time module
 
     /// Calculate the least common multiple
+    ///
+    /// Divides before multiplying, so this only overflows if the result does
     pub(crate) const fn lcm(a: super::UnitNumber, b: super::UnitNumber) -> super::UnitNumber {
         if a == b {
             a
         } else {
-            a * b / gcd(a, b)
+            a / gcd(a, b) * b
         }
     }
 
@@ -20,9 +26,9 @@ mod util {
     const fn gcd(mut a: super::UnitNumber, mut b: super::UnitNumber) -> super::UnitNumber {
         while a != b {
             if a > b {
-                a -= b
+                a -= b;
             } else {
-                b -= a
+                b -= a;
             }
         }
         a
@@ -34,6 +40,7 @@ pub type UnitNumber = usize;
 
 /// The Type representing some Units of Time
 #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TimeUnit(UnitNumber);
 
 impl Debug for TimeUnit {
@@ -42,6 +49,12 @@ impl Debug for TimeUnit {
     }
 }
 
+impl Display for TimeUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
 impl TimeUnit {
     /// Zero Units of Time
     pub const ZERO: TimeUnit = TimeUnit(0);
@@ -49,6 +62,9 @@ impl TimeUnit {
     /// One Unit of Time
     pub const ONE: TimeUnit = TimeUnit(1);
 
+    /// The largest representable Unit of Time
+    pub const MAX: TimeUnit = TimeUnit(UnitNumber::MAX);
+
     /// Get the longer/maximal Unit of Time
     #[must_use]
     pub fn max(self, other: Self) -> Self {
@@ -61,11 +77,72 @@ impl TimeUnit {
         TimeUnit(util::lcm(self.0, other.0))
     }
 
+    /// Add two Units of Time, returning `None` on overflow
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(sum) => Some(TimeUnit(sum)),
+            None => None,
+        }
+    }
+
+    /// Multiply the Units of Time by `factor`, returning `None` on overflow
+    #[must_use]
+    pub const fn checked_mul(self, factor: UnitNumber) -> Option<Self> {
+        match self.0.checked_mul(factor) {
+            Some(product) => Some(TimeUnit(product)),
+            None => None,
+        }
+    }
+
+    /// Subtract two Units of Time, saturating at [`TimeUnit::ZERO`] instead of underflowing
+    #[must_use]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        TimeUnit(self.0.saturating_sub(rhs.0))
+    }
+
     /// Get the Numeric Value of the `TimeUnit` as a `UnitNumber`
     #[must_use]
     pub const fn as_unit(self) -> UnitNumber {
         self.0
     }
+
+    /// Convert a real time `Duration` into Units of Time,
+    /// where `tick` is the real time per Unit of Time
+    ///
+    /// Durations that are not a multiple of `tick` are rounded down to the previous full Unit of Time
+    ///
+    /// # Panics
+    /// When `tick` is zero or the result does not fit in a `UnitNumber`
+    #[must_use]
+    pub fn from_duration(duration: Duration, tick: Duration) -> Self {
+        assert!(!tick.is_zero(), "The tick duration must not be zero");
+
+        let units = duration.as_nanos() / tick.as_nanos();
+
+        TimeUnit(UnitNumber::try_from(units).expect("Duration too long for the given tick"))
+    }
+
+    /// Convert the Units of Time into a real time `Duration`,
+    /// where `tick` is the real time per Unit of Time
+    ///
+    /// The inverse of [`TimeUnit::from_duration`] for durations that are a multiple of `tick`
+    ///
+    /// # Panics
+    /// When the result does not fit in a `Duration`
+    #[must_use]
+    pub fn to_duration(self, tick: Duration) -> Duration {
+        /// Nanoseconds per second
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+        let nanos = tick.as_nanos() * self.0 as u128;
+
+        let secs = u64::try_from(nanos / NANOS_PER_SEC).expect("Duration overflow");
+        let sub_sec_nanos =
+            u32::try_from(nanos % NANOS_PER_SEC).expect("sub-second nanoseconds fit in an u32");
+
+        Duration::new(secs, sub_sec_nanos)
+    }
 }
 
 impl From<UnitNumber> for TimeUnit {
@@ -74,6 +151,43 @@ impl From<UnitNumber> for TimeUnit {
     }
 }
 
+/// Error for a string not describing a [`TimeUnit`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeUnitParseError {
+    /// The string describes a negative number of Units of Time
+    Negative,
+    /// The string is not a non-negative integer
+    Invalid(ParseIntError),
+}
+
+impl Display for TimeUnitParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TimeUnitParseError::Negative => write!(f, "Time can't be negative!"),
+            TimeUnitParseError::Invalid(error) => write!(f, "Invalid time: {error}"),
+        }
+    }
+}
+
+/// Parse a plain non-negative integer, e.g. `"42"`
+impl FromStr for TimeUnit {
+    type Err = TimeUnitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<UnitNumber>().map(TimeUnit).map_err(|error| {
+            let negative = s
+                .strip_prefix('-')
+                .map_or(false, |magnitude| magnitude.parse::<UnitNumber>().is_ok());
+
+            if negative {
+                TimeUnitParseError::Negative
+            } else {
+                TimeUnitParseError::Invalid(error)
+            }
+        })
+    }
+}
+
 impl Div for TimeUnit {
     type Output = UnitNumber;
 
@@ -108,7 +222,7 @@ impl Add for TimeUnit {
 
 impl AddAssign for TimeUnit {
     fn add_assign(&mut self, rhs: Self) {
-        self.0 += rhs.0
+        self.0 += rhs.0;
     }
 }
 
@@ -1,6 +1,6 @@
 //! Module defining the Window and its operations
 
-use core::fmt::Debug;
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
 
 use crate::time::{TimeUnit, UnitNumber};
@@ -26,6 +26,59 @@ pub mod window_types {
 
 mod window_end;
 
+/// The budget groups formed by group boundaries at `phase + k * interval`
+///
+/// The time before the first boundary forms a shortened group 0,
+/// the single place the group arithmetic for phased splitting is implemented
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BudgetGroups {
+    /// the length of a group
+    interval: TimeUnit,
+    /// the shift moving the group boundaries onto multiples of `interval`,
+    /// such that `(time + shift) / interval` is the group of `time`
+    shift: TimeUnit,
+}
+
+impl BudgetGroups {
+    /// Create the budget groups with boundaries at `phase + k * interval`,
+    /// the phase is taken modulo the interval
+    ///
+    /// # Panics
+    /// When the interval is zero
+    pub(crate) fn new(interval: TimeUnit, phase: TimeUnit) -> Self {
+        assert!(
+            interval > TimeUnit::ZERO,
+            "The split interval must be greater than zero"
+        );
+
+        let unit = interval.as_unit();
+        BudgetGroups {
+            interval,
+            shift: TimeUnit::from((unit - phase.as_unit() % unit) % unit),
+        }
+    }
+
+    /// Calculate the group `time` falls into
+    pub(crate) fn group(self, time: TimeUnit) -> UnitNumber {
+        (time + self.shift) / self.interval
+    }
+
+    /// Calculate the start of `group`, the shortened group 0 starts at zero
+    pub(crate) fn start(self, group: UnitNumber) -> TimeUnit {
+        (self.interval * group).saturating_sub(self.shift)
+    }
+
+    /// Calculate the end of `group`, which is the start of the next group
+    pub(crate) fn end(self, group: UnitNumber) -> TimeUnit {
+        self.interval * (group + 1) - self.shift
+    }
+
+    /// Check whether `group` is the shortened group 0 in front of the first boundary
+    pub(crate) fn is_shortened(self, group: UnitNumber) -> bool {
+        group == 0 && self.shift > TimeUnit::ZERO
+    }
+}
+
 pub use window_end::WindowEnd;
 
 /// Type representing a Window based on the papers Definition 1.
@@ -33,6 +86,7 @@ pub use window_end::WindowEnd;
 /// With an extra Type Parameter to indicate the Window type
 // Not Copy to prevent accidental errors due to implicit copy
 #[derive(Debug, Hash, Eq)]
+#[allow(clippy::struct_field_names)] // window_type is the natural name for the marker
 pub struct Window<T> {
     /// The Start point of the Window
     pub start: TimeUnit,
@@ -48,6 +102,13 @@ impl<W> PartialEq for Window<W> {
     }
 }
 
+/// Prints the Window as the half-open interval `[start, end)`
+impl<T> Display for Window<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
 impl<T> Clone for Window<T> {
     fn clone(&self) -> Self {
         Window {
@@ -95,12 +156,55 @@ impl<T> Window<T> {
         }
     }
 
+    /// Calculate the window length for a finite window
+    ///
+    /// Returns `None` if the window is infinite
+    #[must_use]
+    pub fn length_finite(&self) -> Option<TimeUnit> {
+        self.length().as_finite()
+    }
+
+    /// Calculate the window length for a window known to be finite
+    ///
+    /// # Panics
+    /// If the window is infinite
+    #[must_use]
+    pub fn length_or_panic(&self) -> TimeUnit {
+        self.length_finite().unwrap_or_else(|| {
+            panic!(
+                "Expected a finite window, but window starting at {} is infinite",
+                self.start
+            )
+        })
+    }
+
+    /// Scale the window by `factor`, multiplying its start and, if finite, its end
+    ///
+    /// Unlike shifting this also scales the windows length,
+    /// as all windows are scaled by the same factor scaling a sequence of windows
+    /// preserves their order and, for a positive factor, keeps them non-overlapping
+    #[must_use]
+    pub fn scale(&self, factor: UnitNumber) -> Self {
+        let end = match self.end {
+            WindowEnd::Finite(end) => WindowEnd::Finite(end * factor),
+            WindowEnd::Infinite => WindowEnd::Infinite,
+        };
+        Window::new(self.start * factor, end)
+    }
+
     /// Calculate the overlap (Ω) of two windows as defined in Definition 2. of the paper
     #[must_use]
     pub fn overlaps(&self, other: &Self) -> bool {
         !(self.end < other.start || other.end < self.start)
     }
 
+    /// Determine if two windows overlap non-trivially,
+    /// unlike [`Window::overlaps`] windows merely touching at a boundary don't overlap strictly
+    #[must_use]
+    pub fn overlaps_strictly(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
     /// Determine if two windows are adjacent, a special case of overlapping
     ///
     /// Used by `AggregationIterator` to take advantage of the relaxed invariant of `CurveIterator` as opposed to `Curve`
@@ -112,6 +216,23 @@ impl<T> Window<T> {
     /// Calculate the Window delta as defined in Definition 6. of the paper
     #[must_use]
     pub fn delta<Q: WindowType>(supply: &Self, demand: &Window<Q>) -> WindowDeltaResult<T, Q>
+    where
+        T: WindowType,
+    {
+        let result = Window::delta_impl(supply, demand);
+
+        debug_assert_eq!(
+            result.reconstruct_supply(),
+            supply.length(),
+            "The delta of {supply:?} and {demand:?} lost or gained supply: {result:?}"
+        );
+
+        result
+    }
+
+    /// Calculate the Window delta without checking that the supply is preserved,
+    /// see [`Window::delta`]
+    fn delta_impl<Q: WindowType>(supply: &Self, demand: &Window<Q>) -> WindowDeltaResult<T, Q>
     where
         T: WindowType,
     {
@@ -161,6 +282,21 @@ impl<T> Window<T> {
         self.length() == TimeUnit::ZERO
     }
 
+    /// Split the window at `time` into the head `[start, time]` and the tail `[time, end]`
+    ///
+    /// If `time` is at or before the start of the window the head is empty,
+    /// if `time` is at or after the end of the window the tail is empty
+    #[must_use]
+    pub fn split_at(&self, time: TimeUnit) -> (Self, Self) {
+        if time <= self.start {
+            (Window::empty(), self.clone())
+        } else if self.end <= time {
+            (self.clone(), Window::empty())
+        } else {
+            (Window::new(self.start, time), Window::new(time, self.end))
+        }
+    }
+
     /// Calculate the Budget Group that the window falls into
     /// given a splitting interval
     ///
@@ -182,10 +318,21 @@ impl<T> Window<T> {
     }
 
     /**
-    Change the `window_type` of the `Window`
+    Change the `window_type` of the `Window`,
+    analogous to [`Curve::reclassify`](crate::curve::Curve::reclassify)
+
+    This is only meaningful when `T` and `R` share the same semantics,
+    as only the marker type changes while `start` and `end` are kept as is
+
+    ```
+    # use rta_for_fps_lib::window::{Demand, Supply, Window};
+    let demand = Window::<Demand>::new(2, 5);
+    let supply: Window<Supply> = demand.reclassify();
+    assert_eq!(supply, Window::new(2, 5));
+    ```
     */
     #[must_use]
-    pub const fn reclassify<R>(self) -> Window<R> {
+    pub const fn reclassify<R: WindowType>(self) -> Window<R> {
         Window {
             start: self.start,
             end: self.end,
@@ -207,6 +354,18 @@ pub struct WindowDeltaResult<P: WindowType, Q: WindowType> {
     pub remaining_demand: Window<Q>,
 }
 
+impl<P: WindowType, Q: WindowType> WindowDeltaResult<P, Q> {
+    /// Calculate the combined length of the remaining supply head, overlap and remaining supply tail
+    ///
+    /// This equals the length of the original supply window, the supply is only split up
+    #[must_use]
+    pub fn reconstruct_supply(&self) -> WindowEnd {
+        self.remaining_supply_head.length()
+            + self.overlap.length()
+            + self.remaining_supply_tail.length()
+    }
+}
+
 /// Marker Type for Window, indicating a Supply Window
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Supply;
@@ -1,6 +1,7 @@
 //! Module for the Implementation of the `WindowEnd` type (to be renamed)
 
 use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
 use core::iter::Sum;
 use core::ops::{Add, AddAssign, Sub};
 
@@ -30,6 +31,84 @@ impl WindowEnd {
             other
         }
     }
+
+    /// return the maximal value
+    /// Infinite values are always larger than Finite
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        if self < other {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// return the finite value or `None` if infinite
+    ///
+    /// ```
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// # use rta_for_fps_lib::window::WindowEnd;
+    /// assert_eq!(WindowEnd::Finite(TimeUnit::from(5)).as_finite(), Some(TimeUnit::from(5)));
+    /// assert_eq!(WindowEnd::Infinite.as_finite(), None);
+    /// ```
+    #[must_use]
+    pub const fn as_finite(self) -> Option<TimeUnit> {
+        match self {
+            WindowEnd::Finite(time) => Some(time),
+            WindowEnd::Infinite => None,
+        }
+    }
+
+    /// return whether the value is infinite
+    ///
+    /// ```
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// # use rta_for_fps_lib::window::WindowEnd;
+    /// assert!(WindowEnd::Infinite.is_infinite());
+    /// assert!(!WindowEnd::Finite(TimeUnit::from(5)).is_infinite());
+    /// ```
+    #[must_use]
+    pub const fn is_infinite(self) -> bool {
+        matches!(self, WindowEnd::Infinite)
+    }
+
+    /// return the finite value or `default` if infinite
+    ///
+    /// ```
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// # use rta_for_fps_lib::window::WindowEnd;
+    /// let default = TimeUnit::from(10);
+    /// assert_eq!(WindowEnd::Finite(TimeUnit::from(5)).finite_or(default), TimeUnit::from(5));
+    /// assert_eq!(WindowEnd::Infinite.finite_or(default), default);
+    /// ```
+    #[must_use]
+    pub const fn finite_or(self, default: TimeUnit) -> TimeUnit {
+        match self {
+            WindowEnd::Finite(time) => time,
+            WindowEnd::Infinite => default,
+        }
+    }
+
+    /// Add two `WindowEnd`s, returning `None` when the sum of two finite values overflows
+    ///
+    /// ```
+    /// # use rta_for_fps_lib::time::TimeUnit;
+    /// # use rta_for_fps_lib::window::WindowEnd;
+    /// let one = WindowEnd::Finite(TimeUnit::ONE);
+    /// assert_eq!(one.checked_add(one), Some(WindowEnd::Finite(TimeUnit::from(2))));
+    /// assert_eq!(WindowEnd::Finite(TimeUnit::MAX).checked_add(one), None);
+    /// assert_eq!(WindowEnd::Infinite.checked_add(one), Some(WindowEnd::Infinite));
+    /// ```
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match (self, rhs) {
+            (WindowEnd::Finite(us), WindowEnd::Finite(them)) => match us.checked_add(them) {
+                Some(sum) => Some(WindowEnd::Finite(sum)),
+                None => None,
+            },
+            (WindowEnd::Infinite, _) | (_, WindowEnd::Infinite) => Some(WindowEnd::Infinite),
+        }
+    }
 }
 
 impl AddAssign for WindowEnd {
@@ -89,13 +168,30 @@ impl Add for &WindowEnd {
     }
 }
 
+/// Prints the number for a finite `WindowEnd` and `∞` for an infinite one
+impl Display for WindowEnd {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WindowEnd::Finite(end) => Display::fmt(end, f),
+            WindowEnd::Infinite => f.write_str("∞"),
+        }
+    }
+}
+
 impl PartialOrd for WindowEnd {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// `Infinite` is greater than every `Finite` end
+impl Ord for WindowEnd {
+    fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
-            (Self::Infinite, Self::Infinite) => Some(Ordering::Equal),
-            (Self::Infinite, Self::Finite(_)) => Some(Ordering::Greater),
-            (Self::Finite(_), Self::Infinite) => Some(Ordering::Less),
-            (Self::Finite(a), Self::Finite(b)) => a.partial_cmp(b),
+            (Self::Infinite, Self::Infinite) => Ordering::Equal,
+            (Self::Infinite, Self::Finite(_)) => Ordering::Greater,
+            (Self::Finite(_), Self::Infinite) => Ordering::Less,
+            (Self::Finite(a), Self::Finite(b)) => a.cmp(b),
         }
     }
 }
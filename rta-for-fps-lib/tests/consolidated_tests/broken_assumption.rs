@@ -264,5 +264,5 @@ pub fn curve_has_no_non_trivial_overlap<C: CurveType>(c1: &Curve<C>, c2: &Curve<
 }
 
 pub fn window_has_no_non_trivial_overlap<W: WindowType>(w1: &Window<W>, w2: &Window<W>) -> bool {
-    (!w1.overlaps(w2)) || w1.end == w2.start || w2.end == w1.start
+    !w1.overlaps_strictly(w2)
 }
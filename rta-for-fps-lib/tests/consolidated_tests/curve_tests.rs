@@ -1,11 +1,142 @@
 use crate::rta_lib::curve::curve_types::UnspecifiedCurve;
 use crate::rta_lib::curve::Curve;
+use crate::rta_lib::iterators::cache::CachedCurveIterator;
 use crate::rta_lib::iterators::curve::{
-    AggregationIterator, CurveDeltaIterator, CurveSplitIterator,
+    AggregationIterator, CurveDeltaIterator, CurveIter, CurveSplitIterator, IterCurveWrapper,
 };
+use crate::rta_lib::iterators::join::JoinAdjacentIterator;
+use crate::rta_lib::iterators::task::TaskDemandIterator;
+use crate::rta_lib::server::{AggregatedServerDemand, Server, ServerKind, ServerProperties};
+use crate::rta_lib::task::curve_types::TaskDemand;
+use crate::rta_lib::task::Task;
 use crate::rta_lib::time::TimeUnit;
-use crate::rta_lib::window::{Demand, Overlap, Supply, Window};
-use rta_for_fps_lib::iterators::CurveIterator;
+use crate::rta_lib::window::{Demand, Overlap, Supply, Window, WindowEnd};
+use rta_for_fps_lib::iterators::{CurveIterator, EitherCurveIterator, Interleaving};
+use std::cell::Cell;
+
+#[test]
+fn eq_iterator_up_to() {
+    // infinite demand [0,1), [4,5), [8,9), ...
+    let task = Task::new(1, 4, 0);
+
+    let expected = unsafe {
+        Curve::<TaskDemand>::from_windows_unchecked(vec![
+            Window::new(0, 1),
+            Window::new(4, 5),
+            Window::new(8, 9),
+        ])
+    };
+
+    assert!(expected.eq_iterator_up_to(task.into_iter(), TimeUnit::from(12)));
+    assert!(expected.eq_iterator_up_to(task.into_iter(), TimeUnit::from(9)));
+    assert!(!expected.eq_iterator_up_to(task.into_iter(), TimeUnit::from(13)));
+
+    // window straddling the limit on the expected side only
+    let straddling = unsafe {
+        Curve::<TaskDemand>::from_windows_unchecked(vec![
+            Window::new(0, 1),
+            Window::new(4, 5),
+            Window::new(8, 10),
+        ])
+    };
+
+    assert!(straddling.eq_iterator_up_to(task.into_iter(), TimeUnit::from(9)));
+    assert!(!straddling.eq_iterator_up_to(task.into_iter(), TimeUnit::from(10)));
+
+    // window straddling the limit on the iterator side only
+    let clipped = unsafe {
+        Curve::<TaskDemand>::from_windows_unchecked(vec![Window::new(0, 1), Window::new(4, 5)])
+    };
+
+    let long_task = Task::new(2, 4, 0);
+    let long_expected = unsafe {
+        Curve::<TaskDemand>::from_windows_unchecked(vec![Window::new(0, 2), Window::new(4, 5)])
+    };
+
+    assert!(long_expected.eq_iterator_up_to(long_task.into_iter(), TimeUnit::from(5)));
+    assert!(!clipped.eq_iterator_up_to(long_task.into_iter(), TimeUnit::from(5)));
+    assert!(clipped.eq_iterator_up_to(task.into_iter(), TimeUnit::from(8)));
+
+    crate::util::assert_curve_eq_limit(&expected, task.into_iter(), Some(TimeUnit::from(12)));
+}
+
+#[test]
+fn zip_cumulative() {
+    let a = unsafe {
+        Curve::<UnspecifiedCurve<Demand>>::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(5, 7),
+        ])
+    };
+    let b = unsafe {
+        Curve::<UnspecifiedCurve<Supply>>::from_windows_unchecked(vec![
+            Window::new(1, 3),
+            Window::new(5, WindowEnd::Infinite),
+        ])
+    };
+
+    let result: Vec<_> = a
+        .into_iter()
+        .zip_cumulative(b.into_iter())
+        .map(|(time, a, b)| (time.as_unit(), a.as_unit(), b.as_unit()))
+        .collect();
+
+    assert_eq!(
+        result,
+        vec![
+            (0, 0, 0),
+            (1, 1, 0),
+            (2, 2, 1),
+            (3, 2, 2),
+            (5, 2, 2),
+            (7, 4, 4)
+        ]
+    );
+
+    // lazy for infinite curves when bounded by take_while_curve
+    let task = Task::new(1, 4, 0);
+    let result: Vec<_> = task
+        .into_iter()
+        .take_while_curve(|window| window.start < TimeUnit::from(8))
+        .zip_cumulative(Task::new(2, 8, 1).into_iter())
+        .map(|(time, a, b)| (time.as_unit(), a.as_unit(), b.as_unit()))
+        .take(8)
+        .collect();
+
+    assert_eq!(
+        result,
+        vec![
+            (0, 0, 0),
+            (1, 1, 0),
+            (3, 1, 2),
+            (4, 1, 2),
+            (5, 2, 2),
+            (9, 2, 2),
+            (11, 2, 4),
+            (17, 2, 4)
+        ]
+    );
+}
+
+#[test]
+fn aggregate_into_server_demand() {
+    let tasks = &[Task::new(1, 4, 0), Task::new(2, 8, 1)];
+    let server = Server::deferrable(tasks, 3.into(), 10.into());
+
+    let aggregated: Vec<Window<Demand>> =
+        AggregationIterator::new(tasks.iter().map(|task| task.into_iter()).collect())
+            .aggregate_into::<AggregatedServerDemand>()
+            .take_while_curve(|window| window.end <= TimeUnit::from(30))
+            .collect();
+
+    let expected: Vec<Window<Demand>> = server
+        .aggregated_demand_curve_iter()
+        .take_while_curve(|window| window.end <= TimeUnit::from(30))
+        .collect();
+
+    assert_eq!(aggregated, expected);
+    assert!(!aggregated.is_empty());
+}
 
 #[test]
 fn aggregate_curves() {
@@ -102,7 +233,1069 @@ fn split_curves() {
         Window::new(24, 26),
     ];
 
-    let result: Vec<_> = CurveSplitIterator::new(c_p.into_iter(), t_s).collect();
+    let result: Vec<_> = CurveSplitIterator::new(c_p.clone().into_iter(), t_s).collect();
+
+    assert_eq!(result, expected);
+
+    // a zero phase splits like the plain split
+    let result: Vec<_> =
+        CurveSplitIterator::new_with_offset(c_p.into_iter(), t_s, TimeUnit::ZERO).collect();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn split_curves_with_offset() {
+    let c_p: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(2, 4),
+            Window::new(5, 6),
+            Window::new(7, 23),
+            Window::new(24, 26),
+            Window::new(33, WindowEnd::Infinite),
+        ])
+    };
+
+    let expected = vec![
+        Window::new(2, 3),
+        Window::new(3, 4),
+        Window::new(5, 6),
+        Window::new(7, 13),
+        Window::new(13, 23),
+        Window::new(24, 26),
+        Window::new(33, WindowEnd::Infinite),
+    ];
+
+    // a phase of 13 is equivalent to a phase of 3
+    let result: Vec<_> =
+        CurveSplitIterator::new_with_offset(c_p.into_iter(), TimeUnit::from(10), 13.into())
+            .collect();
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn scale_curve() {
+    let curve: Curve<UnspecifiedCurve<Demand>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(1, 3),
+            Window::new(5, 6),
+            Window::new(8, WindowEnd::Infinite),
+        ])
+    };
+
+    let expected: Curve<UnspecifiedCurve<Demand>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(2, 6),
+            Window::new(10, 12),
+            Window::new(16, WindowEnd::Infinite),
+        ])
+    };
+
+    assert_eq!(curve.scale_time(2), expected);
+}
+
+#[test]
+fn scale_curve_task_round_trip() {
+    let task = Task::new(2, 5, 1);
+    let scaled_task = task.scale_time(3);
+
+    let curve: Curve<TaskDemand> = task.bounded_demand_iter(TimeUnit::from(20)).collect_curve();
+    let scaled_curve: Curve<TaskDemand> = scaled_task
+        .bounded_demand_iter(TimeUnit::from(60))
+        .collect_curve();
+
+    assert_eq!(curve.clone().stretch(3), scaled_curve);
+    assert_eq!(curve.scale_time(3), scaled_curve);
+}
+
+#[test]
+fn scale_server_with_tasks() {
+    let tasks = [Task::new(1, 5, 0), Task::new(2, 8, 1)];
+    let server = Server::new(
+        &tasks,
+        TimeUnit::from(2),
+        TimeUnit::from(4),
+        ServerKind::Deferrable,
+    );
+
+    let scaled = server.scale_time(3);
+
+    assert_eq!(scaled.tasks[1].demand, TimeUnit::from(6));
+    assert_eq!(scaled.tasks[1].interval, TimeUnit::from(24));
+    assert_eq!(scaled.tasks[1].offset, TimeUnit::from(3));
+    assert_eq!(scaled.properties.capacity, TimeUnit::from(6));
+    assert_eq!(scaled.properties.interval, TimeUnit::from(12));
+
+    let demand: Curve<_> = server
+        .constraint_demand_curve_iter()
+        .take_while_curve(|window| window.start < TimeUnit::from(40))
+        .collect_curve();
+    let scaled_demand: Curve<_> = scaled
+        .as_server()
+        .constraint_demand_curve_iter()
+        .take_while_curve(|window| window.start < TimeUnit::from(120))
+        .collect_curve();
+
+    assert_eq!(demand.stretch(3), scaled_demand);
+}
+
+#[test]
+#[should_panic(expected = "Can't scale the time of a Task by 0!")]
+fn scale_task_by_zero() {
+    let _ = Task::new(2, 5, 1).scale_time(0);
+}
+
+#[test]
+#[should_panic(expected = "Can't scale the time of Server Properties by 0!")]
+fn scale_server_by_zero() {
+    let tasks = [Task::new(1, 5, 0)];
+    let server = Server::new(
+        &tasks,
+        TimeUnit::from(2),
+        TimeUnit::from(10),
+        ServerKind::Deferrable,
+    );
+
+    let _ = server.scale_time(0);
+}
+
+#[test]
+fn merge_adjacent_windows() {
+    let curve: Curve<UnspecifiedCurve<Demand>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(2, 4),
+            Window::new(5, 6),
+            Window::new(7, 8),
+            Window::new(8, WindowEnd::Infinite),
+        ])
+    };
+
+    let expected: Curve<UnspecifiedCurve<Demand>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 4),
+            Window::new(5, 6),
+            Window::new(7, WindowEnd::Infinite),
+        ])
+    };
+
+    assert_eq!(curve.merge_adjacent(), expected);
+}
+
+#[test]
+fn aggregate_boxed_curves() {
+    let task = Task::new(1, 5, 0);
+    let curve: Curve<TaskDemand> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(0, 2), Window::new(5, 8)]) };
+
+    let result: Curve<TaskDemand> = AggregationIterator::new(vec![
+        task.into_iter()
+            .take_while_curve(|window| window.end <= TimeUnit::from(10))
+            .boxed(),
+        curve.into_iter().boxed(),
+    ])
+    .collect_curve();
+
+    let expected =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(0, 3), Window::new(5, 9)]) };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn delta_remaining_demand() {
+    let supply: Curve<UnspecifiedCurve<Supply>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(0, 2)]) };
+
+    let demand: Curve<UnspecifiedCurve<Demand>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(1, 4), Window::new(5, 6)]) };
+
+    let expected: Curve<UnspecifiedCurve<Demand>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(2, 4), Window::new(5, 6)]) };
+
+    let result = CurveDeltaIterator::new(supply.into_iter(), demand.into_iter()).remaining_demand();
+
+    crate::util::assert_curve_eq(&expected, result);
+}
+
+#[test]
+fn partition_periodic_window_ending_on_limit() {
+    let properties = ServerProperties {
+        capacity: TimeUnit::from(2),
+        interval: TimeUnit::from(10),
+        server_type: ServerKind::Periodic,
+        phase: TimeUnit::ZERO,
+    };
+
+    // limit of group 1 is 1 * 10 + 2 = 12
+    let curve: Curve<UnspecifiedCurve<Demand>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(10, 12), Window::new(14, 15)]) };
+
+    let result = curve.partition(1, properties);
+
+    assert_eq!(result.index, 1);
+    assert!(result.head.is_empty());
+    assert_eq!(result.tail, Window::new(14, 15));
+
+    // a window crossing the limit is split at the limit
+    let curve: Curve<UnspecifiedCurve<Demand>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(11, 13)]) };
+
+    let result = curve.partition(1, properties);
+
+    assert_eq!(result.index, 0);
+    assert_eq!(result.head, Window::new(11, 12));
+    assert_eq!(result.tail, Window::new(12, 13));
+}
+
+#[test]
+fn partition_infinite_window() {
+    let curve: Curve<UnspecifiedCurve<Demand>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(10, 11),
+            Window::new(13, WindowEnd::Infinite),
+        ])
+    };
+
+    // the deferrable budget remaining after the first window ends at 16,
+    // the periodic budget at 1 * 10 + 4 = 14
+    for &(server_type, split) in &[(ServerKind::Deferrable, 16), (ServerKind::Periodic, 14)] {
+        let properties = ServerProperties {
+            capacity: TimeUnit::from(4),
+            interval: TimeUnit::from(10),
+            server_type,
+            phase: TimeUnit::ZERO,
+        };
+
+        // the infinite window consumes the remaining budget, spilling the infinite rest
+        let result = curve.partition(1, properties);
+
+        assert_eq!(result.index, 1, "{:?}", server_type);
+        assert_eq!(result.head, Window::new(13, split), "{:?}", server_type);
+        assert_eq!(
+            result.tail,
+            Window::new(split, WindowEnd::Infinite),
+            "{:?}",
+            server_type
+        );
+    }
+}
+
+#[test]
+fn prefix_fitting() {
+    let curve: Curve<UnspecifiedCurve<Demand>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(3, 4),
+            Window::new(6, WindowEnd::Infinite),
+        ])
+    };
+
+    assert_eq!(curve.prefix_fitting(TimeUnit::ZERO), (0, TimeUnit::ZERO));
+    assert_eq!(
+        curve.prefix_fitting(TimeUnit::from(1)),
+        (0, TimeUnit::from(1))
+    );
+    assert_eq!(curve.prefix_fitting(TimeUnit::from(2)), (1, TimeUnit::ZERO));
+    assert_eq!(curve.prefix_fitting(TimeUnit::from(3)), (2, TimeUnit::ZERO));
+    // the infinite window never fits
+    assert_eq!(
+        curve.prefix_fitting(TimeUnit::from(10)),
+        (2, TimeUnit::from(7))
+    );
+
+    let empty: Curve<UnspecifiedCurve<Demand>> = Curve::empty();
+    assert_eq!(
+        empty.prefix_fitting(TimeUnit::from(5)),
+        (0, TimeUnit::from(5))
+    );
+}
+
+#[test]
+fn union_curves() {
+    let c1: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(5, 8),
+            Window::new(12, 13),
+            Window::new(20, WindowEnd::Infinite),
+        ])
+    };
+
+    let c2: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(2, 3),
+            Window::new(6, 7),
+            Window::new(10, 11),
+            Window::new(12, 15),
+            Window::new(22, 25),
+        ])
+    };
+
+    let expected: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 3),
+            Window::new(5, 8),
+            Window::new(10, 11),
+            Window::new(12, 15),
+            Window::new(20, WindowEnd::Infinite),
+        ])
+    };
+
+    assert_eq!(c1.union(&c2), expected);
+    assert_eq!(c2.union(&c1), expected);
+}
+
+#[test]
+fn order_curves() {
+    let a: Curve<UnspecifiedCurve<Supply>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(0, 4), Window::new(6, 8)]) };
+    let b: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![Window::new(0, 4), Window::new(6, WindowEnd::Infinite)])
+    };
+    // same capacity as a, but split differently
+    let c: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(3, 5),
+            Window::new(6, 8),
+        ])
+    };
+
+    assert!(a < b);
+    assert!(c < a);
+    assert_eq!(a.cmp(&a.clone()), core::cmp::Ordering::Equal);
+
+    let mut curves = vec![b.clone(), a.clone(), c.clone(), a.clone()];
+    curves.sort();
+    curves.dedup();
+
+    assert_eq!(curves, vec![c, a, b]);
+
+    assert!(WindowEnd::Finite(TimeUnit::from(usize::MAX)) < WindowEnd::Infinite);
+}
+
+#[test]
+fn edit_curve_in_place() {
+    let mut curve: Curve<UnspecifiedCurve<Supply>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(0, 4), Window::new(6, 8)]) };
+
+    // Safety: shifting all windows by the same amount keeps them ordered and non-overlapping
+    unsafe {
+        curve.as_mut_windows().iter_mut().for_each(|window| {
+            window.start += TimeUnit::from(2);
+            window.end = window.end + TimeUnit::from(2);
+        });
+    }
+
+    let expected: Curve<UnspecifiedCurve<Supply>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(2, 6), Window::new(8, 10)]) };
+
+    assert_eq!(curve, expected);
+}
+
+#[test]
+fn capacity() {
+    let finite: Curve<UnspecifiedCurve<Supply>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(0, 2), Window::new(4, 7)]) };
+    assert_eq!(finite.capacity(), WindowEnd::Finite(TimeUnit::from(5)));
+
+    let infinite: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![Window::new(0, 2), Window::new(4, WindowEnd::Infinite)])
+    };
+    assert_eq!(infinite.capacity(), WindowEnd::Infinite);
+}
+
+#[test]
+fn to_step_points() {
+    let curve: Curve<UnspecifiedCurve<Demand>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(1, 3),
+            Window::new(5, 6),
+            Window::new(8, WindowEnd::Infinite),
+        ])
+    };
+
+    let expected: Vec<(TimeUnit, TimeUnit)> = vec![
+        (1.into(), 0.into()),
+        (3.into(), 2.into()),
+        (5.into(), 2.into()),
+        (6.into(), 3.into()),
+        (8.into(), 3.into()),
+    ];
+
+    assert_eq!(curve.to_step_points(), expected);
+    assert!(Curve::<UnspecifiedCurve<Demand>>::empty()
+        .to_step_points()
+        .is_empty());
+}
+
+#[test]
+fn window_length_and_gap_extremes() {
+    let curve: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(5, 9),
+            Window::new(10, 11),
+        ])
+    };
+    assert_eq!(
+        curve.max_window_length(),
+        WindowEnd::Finite(TimeUnit::from(4))
+    );
+    assert_eq!(curve.min_gap(), Some(TimeUnit::ONE));
+
+    let infinite: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![Window::new(0, 2), Window::new(4, WindowEnd::Infinite)])
+    };
+    assert_eq!(infinite.max_window_length(), WindowEnd::Infinite);
+    assert_eq!(infinite.min_gap(), Some(TimeUnit::from(2)));
+
+    let single: Curve<UnspecifiedCurve<Supply>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(3, 5)]) };
+    assert_eq!(single.min_gap(), None);
+
+    let empty: Curve<UnspecifiedCurve<Supply>> = Curve::empty();
+    assert_eq!(empty.max_window_length(), WindowEnd::Finite(TimeUnit::ZERO));
+}
+
+#[test]
+#[should_panic(expected = "window starting at 4 is infinite")]
+fn sum_length_while_infinite() {
+    let infinite: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![Window::new(0, 2), Window::new(4, WindowEnd::Infinite)])
+    };
+
+    let _ = infinite.into_iter().sum_length_while(|_| true);
+}
+
+#[test]
+fn split_curve() {
+    let curve: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(1, 2),
+            Window::new(3, 7),
+            Window::new(13, WindowEnd::Infinite),
+        ])
+    };
+
+    let groups = curve.split(TimeUnit::from(5));
+
+    let expected: Vec<(usize, Curve<_>)> = unsafe {
+        vec![
+            (
+                0,
+                Curve::from_windows_unchecked(vec![Window::new(1, 2), Window::new(3, 5)]),
+            ),
+            // the window crossing the boundary at 5 continues in the next group
+            (1, Curve::from_windows_unchecked(vec![Window::new(5, 7)])),
+            (2, Curve::from_windows_unchecked(vec![Window::new(13, 15)])),
+            (
+                3,
+                Curve::from_windows_unchecked(vec![Window::new(15, WindowEnd::Infinite)]),
+            ),
+        ]
+    };
+
+    assert_eq!(groups.into_iter().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn capacity_per_interval() {
+    let curve: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(1, 2),
+            Window::new(3, 12),
+            Window::new(21, 23),
+        ])
+    };
+
+    let capacities = curve.capacity_per_interval(TimeUnit::from(5));
+
+    // the window [3, 12) straddles the boundaries at 5 and 10
+    let expected = vec![
+        (0, TimeUnit::from(3)),
+        (1, TimeUnit::from(5)),
+        (2, TimeUnit::from(2)),
+        (4, TimeUnit::from(2)),
+    ];
+
+    assert_eq!(capacities.into_iter().collect::<Vec<_>>(), expected);
+
+    let total: TimeUnit = curve
+        .capacity_per_interval(TimeUnit::from(5))
+        .values()
+        .copied()
+        .sum();
+    assert_eq!(WindowEnd::Finite(total), curve.capacity());
+}
+
+#[test]
+#[should_panic]
+fn capacity_per_interval_infinite() {
+    let curve: Curve<UnspecifiedCurve<Supply>> = Curve::new(Window::new(3, WindowEnd::Infinite));
+
+    let _ = curve.capacity_per_interval(TimeUnit::from(5));
+}
+
+#[test]
+fn push_scrambled_windows() {
+    let mut curve: Curve<UnspecifiedCurve<Demand>> = Curve::empty();
+
+    curve.extend(vec![
+        Window::new(10, 12),
+        Window::new(0, 2),
+        Window::new(5, 6),
+        // adjacent to [0, 2)
+        Window::new(2, 3),
+        // overlapping [10, 12)
+        Window::new(11, 14),
+        Window::new(20, WindowEnd::Infinite),
+        Window::new(4, 4),
+    ]);
+
+    let expected = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 3),
+            Window::new(5, 6),
+            Window::new(10, 14),
+            Window::new(20, WindowEnd::Infinite),
+        ])
+    };
+
+    assert_eq!(curve, expected);
+
+    // joining multiple windows at once
+    curve.push(Window::new(13, 25));
+    curve.push(Window::new(3, 5));
+
+    let expected = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 6),
+            Window::new(10, WindowEnd::Infinite),
+        ])
+    };
+
+    assert_eq!(curve, expected);
+}
+
+#[test]
+fn clamp_to() {
+    let curve: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(1, 2),
+            Window::new(3, 7),
+            Window::new(13, WindowEnd::Infinite),
+        ])
+    };
+
+    // the straddling window is truncated
+    assert_eq!(
+        curve
+            .clone()
+            .into_iter()
+            .clamp_to(TimeUnit::from(5))
+            .collect_pairs(),
+        vec![
+            (TimeUnit::from(1), WindowEnd::from(2)),
+            (TimeUnit::from(3), WindowEnd::from(5))
+        ]
+    );
+
+    // a window ending at the horizon is kept whole
+    assert_eq!(
+        curve
+            .clone()
+            .into_iter()
+            .clamp_to(TimeUnit::from(7))
+            .collect_pairs(),
+        vec![
+            (TimeUnit::from(1), WindowEnd::from(2)),
+            (TimeUnit::from(3), WindowEnd::from(7))
+        ]
+    );
+
+    // an infinite window is made finite
+    let clamped: Curve<_> = curve
+        .into_iter()
+        .clamp_to(TimeUnit::from(20))
+        .collect_curve();
+    assert_eq!(clamped.capacity(), WindowEnd::from(12));
+    assert_eq!(clamped.as_windows().last(), Some(&Window::new(13, 20)));
+
+    // an infinite curve becomes finite
+    let task = Task::new(1, 4, 0);
+    assert_eq!(
+        task.into_iter()
+            .clamp_to(TimeUnit::from(10))
+            .collect_pairs()
+            .len(),
+        3
+    );
+}
+
+#[test]
+fn windows_in_range() {
+    let curve: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(4, 8),
+            Window::new(10, 12),
+            Window::new(14, WindowEnd::Infinite),
+        ])
+    };
+
+    // both ends fall inside windows
+    assert_eq!(
+        curve.windows_in_range(TimeUnit::from(5), TimeUnit::from(15)),
+        vec![Window::new(5, 8), Window::new(10, 12), Window::new(14, 15)]
+    );
+
+    // windows only touching the range are excluded
+    assert_eq!(
+        curve.windows_in_range(TimeUnit::from(2), TimeUnit::from(4)),
+        vec![]
+    );
+
+    // an empty range
+    assert_eq!(
+        curve.windows_in_range(TimeUnit::from(6), TimeUnit::from(6)),
+        vec![]
+    );
+}
+
+#[test]
+fn take_up_to_capacity() {
+    let curve: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(4, 8),
+            Window::new(10, WindowEnd::Infinite),
+        ])
+    };
+
+    // truncated inside the second window
+    let expected: Curve<UnspecifiedCurve<Supply>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(0, 2), Window::new(4, 7)]) };
+
+    let result: Curve<_> = curve
+        .clone()
+        .into_iter()
+        .take_up_to_capacity(5.into())
+        .collect_curve();
+    assert_eq!(result, expected);
+
+    // truncated inside the infinite window
+    let expected: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(4, 8),
+            Window::new(10, 13),
+        ])
+    };
+
+    let result: Curve<_> = curve
+        .into_iter()
+        .take_up_to_capacity(9.into())
+        .collect_curve();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn invert_curve_up_to() {
+    let curve: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(2, 4),
+            Window::new(6, 8),
+            Window::new(12, WindowEnd::Infinite),
+        ])
+    };
+
+    let expected: Curve<UnspecifiedCurve<Demand>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(4, 6),
+            Window::new(8, 12),
+        ])
+    };
+    assert_eq!(curve.invert_up_to(TimeUnit::from(20)), expected);
+
+    // the limit falls into a gap
+    let expected: Curve<UnspecifiedCurve<Demand>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(0, 2), Window::new(4, 5)]) };
+    assert_eq!(curve.invert_up_to(TimeUnit::from(5)), expected);
+
+    // the complement of an empty curve is the whole domain
+    let empty: Curve<UnspecifiedCurve<Supply>> = Curve::empty();
+    let expected: Curve<UnspecifiedCurve<Demand>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(0, 5)]) };
+    assert_eq!(empty.invert_up_to(TimeUnit::from(5)), expected);
+}
+
+#[test]
+fn windows_overlap_with() {
+    let curve: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(4, 6),
+            Window::new(10, WindowEnd::Infinite),
+        ])
+    };
+    let touching: Curve<UnspecifiedCurve<Demand>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(2, 4), Window::new(6, 10)]) };
+    let overlapping: Curve<UnspecifiedCurve<Demand>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(2, 4), Window::new(7, 11)]) };
+
+    // merely touching windows don't overlap
+    assert!(!curve
+        .clone()
+        .into_iter()
+        .windows_overlap_with(touching.into_iter(), 20.into()));
+
+    assert!(curve
+        .clone()
+        .into_iter()
+        .windows_overlap_with(overlapping.clone().into_iter(), 20.into()));
+
+    // overlap starting at or after up_to is ignored
+    assert!(!curve
+        .into_iter()
+        .windows_overlap_with(overlapping.into_iter(), 10.into()));
+}
+
+#[test]
+fn interleave_count() {
+    let curve: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(4, 6),
+            Window::new(10, WindowEnd::Infinite),
+        ])
+    };
+    let touching: Curve<UnspecifiedCurve<Demand>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(2, 4), Window::new(6, 10)]) };
+    let overlapping: Curve<UnspecifiedCurve<Demand>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(2, 4), Window::new(7, 11)]) };
+
+    assert_eq!(
+        curve
+            .clone()
+            .into_iter()
+            .interleave_count(touching.into_iter(), 20.into()),
+        Interleaving {
+            switches: 4,
+            overlaps: 0
+        }
+    );
+
+    // (10, ∞) overlaps with (7, 11)
+    assert_eq!(
+        curve
+            .clone()
+            .into_iter()
+            .interleave_count(overlapping.clone().into_iter(), 20.into()),
+        Interleaving {
+            switches: 4,
+            overlaps: 1
+        }
+    );
+
+    // windows starting at or after up_to are ignored
+    assert_eq!(
+        curve
+            .clone()
+            .into_iter()
+            .interleave_count(overlapping.into_iter(), 10.into()),
+        Interleaving {
+            switches: 3,
+            overlaps: 0
+        }
+    );
+
+    // every window overlaps its copy
+    assert_eq!(
+        curve
+            .clone()
+            .into_iter()
+            .interleave_count(curve.into_iter(), 20.into()),
+        Interleaving {
+            switches: 5,
+            overlaps: 3
+        }
+    );
+}
+
+#[test]
+fn collect_delta_into_three() {
+    // Example 3.
+    let c_p: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 5),
+            Window::new(12, 15),
+            Window::new(22, 24),
+            Window::new(30, 35),
+        ])
+    };
+
+    let c_q: Curve<UnspecifiedCurve<Demand>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(2, 4),
+            Window::new(14, 17),
+            Window::new(22, 24),
+        ])
+    };
+
+    // with a limit past both curves the result matches collect_delta
+    let expected = CurveDeltaIterator::new(c_p.clone().into_iter(), c_q.clone().into_iter())
+        .collect_delta::<UnspecifiedCurve<Overlap<Supply, Demand>>>();
+    let (supply, overlap, demand) =
+        CurveDeltaIterator::new(c_p.clone().into_iter(), c_q.clone().into_iter())
+            .collect_into_three(100.into());
+
+    assert_eq!(supply, expected.remaining_supply);
+    assert_eq!(overlap, expected.overlap);
+    assert_eq!(demand.as_windows(), expected.remaining_demand.as_windows());
+
+    // windows crossing the limit are truncated
+    let (supply, overlap, demand) =
+        CurveDeltaIterator::new(c_p.into_iter(), c_q.into_iter()).collect_into_three(23.into());
+
+    let expected_supply = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(4, 5),
+            Window::new(12, 14),
+        ])
+    };
+    let expected_overlap = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(2, 4),
+            Window::new(14, 15),
+            Window::new(22, 23),
+        ])
+    };
+
+    assert_eq!(supply, expected_supply);
+    assert_eq!(overlap, expected_overlap);
+    assert!(demand.is_empty());
+
+    // terminates for infinite curves
+    let c_p: Curve<UnspecifiedCurve<Supply>> = Curve::new(Window::new(10, WindowEnd::Infinite));
+    let task = Task::new(1, 5, 0);
+
+    let (supply, overlap, demand) =
+        CurveDeltaIterator::new(c_p.into_iter(), task.into_iter()).collect_into_three(20.into());
+
+    let expected_supply =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(13, 15), Window::new(16, 20)]) };
+    let expected_overlap =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(10, 13), Window::new(15, 16)]) };
+
+    assert_eq!(supply, expected_supply);
+    assert_eq!(overlap, expected_overlap);
+    assert!(demand.is_empty());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn curve_with_empty_window() {
+    let _ = unsafe {
+        Curve::<UnspecifiedCurve<Supply>>::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(4, 4),
+        ])
+    };
+}
+
+#[test]
+fn cumulative_at() {
+    let curve: Curve<UnspecifiedCurve<Supply>> = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(2, 4),
+            Window::new(6, 9),
+            Window::new(12, WindowEnd::Infinite),
+        ])
+    };
+
+    let times: Vec<TimeUnit> = [0, 2, 3, 4, 5, 9, 9, 12, 20]
+        .iter()
+        .map(|&time| TimeUnit::from(time))
+        .collect();
+    let expected: Vec<TimeUnit> = [0, 0, 1, 2, 2, 5, 5, 5, 13]
+        .iter()
+        .map(|&length| TimeUnit::from(length))
+        .collect();
+
+    assert_eq!(curve.cumulative_at(&times), expected);
+    assert!(curve.cumulative_at(&[]).is_empty());
+}
+
+#[test]
+fn iter_windows() {
+    let supply: Curve<UnspecifiedCurve<Supply>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(0, 5), Window::new(12, 15)]) };
+
+    let first: Curve<UnspecifiedCurve<Demand>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(2, 4)]) };
+    let second: Curve<UnspecifiedCurve<Demand>> =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(13, 14)]) };
+
+    // the same supply can be reused for multiple deltas
+    let first_delta = CurveDeltaIterator::new(supply.iter_windows(), first.into_iter())
+        .collect_delta::<UnspecifiedCurve<Overlap<Supply, Demand>>>();
+    let second_delta = CurveDeltaIterator::new(supply.iter_windows(), second.into_iter())
+        .collect_delta::<UnspecifiedCurve<Overlap<Supply, Demand>>>();
+
+    assert_eq!(first_delta.overlap.as_windows(), &[Window::new(2, 4)]);
+    assert_eq!(second_delta.overlap.as_windows(), &[Window::new(13, 14)]);
+
+    // usable as a plain Iterator as well
+    let windows: Vec<_> = supply.iter_windows().collect();
+    assert_eq!(windows, supply.as_windows());
+    assert_eq!(supply.iter_windows().collect_curve::<Curve<_>>(), supply);
+}
+
+#[test]
+fn debug_check_invariants() {
+    // adjacent windows are allowed by the CurveIterator invariants
+    let windows = vec![
+        Window::<Supply>::new(0, 2),
+        Window::new(2, 4),
+        Window::new(6, WindowEnd::Infinite),
+    ];
+    let iter = unsafe {
+        IterCurveWrapper::<_, UnspecifiedCurve<Supply>>::new(windows.clone().into_iter())
+    };
+
+    let result: Vec<_> = iter.debug_check_invariants().into_iterator().collect();
+    assert_eq!(result, windows);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn debug_check_invariants_overlap() {
+    let windows = vec![Window::<Supply>::new(0, 4), Window::new(2, 6)];
+    let iter = unsafe { IterCurveWrapper::<_, UnspecifiedCurve<Supply>>::new(windows.into_iter()) };
+
+    let _ = iter.debug_check_invariants().count_windows_up_to(10.into());
+}
+
+#[test]
+fn cached_curve_iterator() {
+    let computed = Cell::new(0);
+
+    let task = Task::new(1, 5, 0);
+    let mut cached = CachedCurveIterator::new(
+        task.into_iter()
+            .inspect_windows(|_| computed.set(computed.get() + 1)),
+    );
+
+    let first = cached.next_window();
+    let clone = cached.clone();
+
+    let expected = vec![Window::new(0, 1), Window::new(5, 6), Window::new(10, 11)];
+
+    let restarted: Vec<_> = cached.restart().into_iterator().take(3).collect();
+    assert_eq!(restarted, expected);
+    assert_eq!(first, Some(expected[0].clone()));
+
+    // the clone continues after the first window, replaying the cache
+    let continued: Vec<_> = clone.into_iterator().take(2).collect();
+    assert_eq!(continued, expected[1..]);
+
+    // every window was only calculated once
+    assert_eq!(computed.get(), 3);
+}
+
+#[test]
+fn join_adjacent_checked() {
+    let windows = vec![
+        Window::<Supply>::new(0, 2),
+        Window::new(2, 4),
+        Window::new(6, 8),
+    ];
+    let joined: JoinAdjacentIterator<_, _, UnspecifiedCurve<Supply>> =
+        unsafe { JoinAdjacentIterator::new_checked(windows.into_iter()) };
+
+    let result: Vec<_> = joined.into_iterator().collect();
+    assert_eq!(result, vec![Window::new(0, 4), Window::new(6, 8)]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn join_adjacent_checked_empty_window() {
+    // the unchecked JoinAdjacentIterator would pass the empty window through
+    let windows = vec![
+        Window::<Supply>::new(0, 2),
+        Window::new(3, 3),
+        Window::new(6, 8),
+    ];
+    let joined: JoinAdjacentIterator<_, _, UnspecifiedCurve<Supply>> =
+        unsafe { JoinAdjacentIterator::new_checked(windows.into_iter()) };
+
+    let _ = joined.count_windows_up_to(10.into());
+}
+
+#[test]
+fn either_curve_iterator() {
+    let task = Task::new(1, 5, 0);
+    let curve: Curve<TaskDemand> = task.bounded_demand_iter(TimeUnit::from(10)).collect_curve();
+
+    let left: EitherCurveIterator<_, CurveIter<TaskDemand>> =
+        Ok(task.bounded_demand_iter(TimeUnit::from(10))).into();
+    let right: EitherCurveIterator<TaskDemandIterator, _> = Err(curve.clone().into_iter()).into();
+
+    assert!(matches!(left, EitherCurveIterator::Left(_)));
+    assert!(matches!(right, EitherCurveIterator::Right(_)));
+
+    let to_curve = |either: EitherCurveIterator<TaskDemandIterator, CurveIter<TaskDemand>>| {
+        either.map_either(
+            CurveIterator::collect_curve::<Curve<_>>,
+            CurveIterator::collect_curve,
+        )
+    };
+
+    assert_eq!(to_curve(left), curve);
+    assert_eq!(to_curve(right), curve);
+}
+
+#[test]
+fn flat_map_windows() {
+    let task = Task::new(3, 10, 0);
+
+    // split every window into windows of length one
+    let split = task
+        .bounded_demand_iter(TimeUnit::from(30))
+        .flat_map_windows::<TaskDemand, _, _>(|window| {
+            let end = window.end.as_finite().expect("demand windows are finite");
+            (window.start.as_unit()..end.as_unit()).map(|start| Window::new(start, start + 1))
+        });
+
+    let windows: Vec<_> = split.clone().into_iterator().collect();
+    assert_eq!(windows.len(), 9);
+    assert_eq!(
+        windows[..3],
+        [Window::new(0, 1), Window::new(1, 2), Window::new(2, 3)]
+    );
+
+    let joined: Curve<TaskDemand> = split.normalize().collect_curve();
+    let expected: Curve<TaskDemand> = task.bounded_demand_iter(TimeUnit::from(30)).collect_curve();
+    assert_eq!(joined, expected);
+}
+
+#[test]
+#[should_panic(expected = "starting before the end of the previous window")]
+fn flat_map_windows_overlap() {
+    let task = Task::new(3, 10, 0);
+
+    // duplicating every window violates the CurveIterator invariants
+    let duplicated = task
+        .into_iter()
+        .flat_map_windows::<TaskDemand, _, _>(|window| vec![window.clone(), window]);
+
+    let _ = duplicated.count_windows_up_to(30.into());
+}
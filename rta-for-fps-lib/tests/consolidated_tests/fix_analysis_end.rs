@@ -40,3 +40,29 @@ fn analysis_with_swh_plus_offset() {
 
     assert_eq!(response_time, expected_response_time)
 }
+
+#[test]
+fn analysis_end_variants() {
+    let task1 = &[Task::new(2, 4, 3)];
+    let task2 = &[Task::new(1, 4, 0)];
+
+    let servers = &[
+        Server::new(task1, 2.into(), 4.into(), Deferrable),
+        Server::new(task2, 1.into(), 4.into(), Deferrable),
+    ];
+
+    let system = System::new(servers);
+
+    assert_eq!(
+        system.analysis_end_with_margin(1, 5.into()),
+        TimeUnit::from(12)
+    );
+
+    let end = system.analysis_end_for_jobs(0, 0, 3);
+    assert_eq!(end, TimeUnit::from(15));
+
+    let response_time = Task::fixed_worst_case_response_time(&system, 0, 0, end);
+    let expected_response_time: TimeUnit = 2.into();
+
+    assert_eq!(response_time, expected_response_time)
+}
@@ -92,3 +92,44 @@ pub fn fixed() {
 
     assert_eq!(fixed_actual_curve, expected_curve);
 }
+
+#[test]
+pub fn all_actual_execution_curves() {
+    let task1 = &[Task::new(1, 8, 2)];
+    let task2 = &[Task::new(2, 4, 2)];
+    let task3 = &[Task::new(1, 4, 2)];
+    let server1 = Server::new(task1, 1.into(), 8.into(), Deferrable);
+    let server2 = Server::new(task2, 2.into(), 4.into(), Deferrable);
+    let server3 = Server::new(task3, 1.into(), 4.into(), Deferrable);
+    let servers = [server1, server2, server3];
+    let system = System::new(&servers);
+
+    let up_to = TimeUnit::from(18);
+
+    let original: Vec<Curve<_>> = system
+        .all_original_actual_execution_curves()
+        .map(|curve| curve.take_while_curve(|w| w.end <= up_to).collect_curve())
+        .collect();
+
+    let fixed: Vec<Curve<_>> = system
+        .all_fixed_actual_execution_curves()
+        .map(|curve| curve.take_while_curve(|w| w.end <= up_to).collect_curve())
+        .collect();
+
+    assert_eq!(original.len(), servers.len());
+    assert_eq!(fixed.len(), servers.len());
+
+    for server_index in 0..servers.len() {
+        let expected_original: Curve<_> = system
+            .original_actual_execution_curve_iter(server_index)
+            .take_while_curve(|w| w.end <= up_to)
+            .collect_curve();
+        assert_eq!(original[server_index], expected_original);
+
+        let expected_fixed: Curve<_> = system
+            .fixed_actual_execution_curve_iter(server_index)
+            .take_while_curve(|w| w.end <= up_to)
+            .collect_curve();
+        assert_eq!(fixed[server_index], expected_fixed);
+    }
+}
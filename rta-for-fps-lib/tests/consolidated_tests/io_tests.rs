@@ -0,0 +1,126 @@
+use crate::rta_lib::io::{parse_tasks_csv, ParseError};
+use crate::rta_lib::task::TaskError;
+use crate::rta_lib::time::TimeUnit;
+
+#[test]
+fn parse_csv() {
+    let csv = "\
+# tasks of server 1
+demand, interval, offset
+1,4,0
+
+2,8,3
+";
+
+    let tasks = parse_tasks_csv(csv.as_bytes()).unwrap();
+
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].demand, TimeUnit::from(1));
+    assert_eq!(tasks[0].interval, TimeUnit::from(4));
+    assert_eq!(tasks[0].offset, TimeUnit::from(0));
+    assert_eq!(tasks[1].demand, TimeUnit::from(2));
+    assert_eq!(tasks[1].interval, TimeUnit::from(8));
+    assert_eq!(tasks[1].offset, TimeUnit::from(3));
+}
+
+#[test]
+fn parse_csv_errors() {
+    match parse_tasks_csv("1,4,0\n1,4\n".as_bytes()) {
+        Err(ParseError::FieldCount { line: 2, found: 2 }) => {}
+        other => panic!("Unexpected {:?}", other),
+    }
+
+    match parse_tasks_csv("1,4,0\n1,x,0\n".as_bytes()) {
+        Err(ParseError::InvalidNumber {
+            line: 2,
+            field: "interval",
+            value,
+        }) => assert_eq!(value, "x"),
+        other => panic!("Unexpected {:?}", other),
+    }
+
+    let error = parse_tasks_csv("\n5,4,0\n".as_bytes()).unwrap_err();
+    match error {
+        ParseError::InvalidTask {
+            line: 2,
+            error: TaskError::IntervalShorterThanDemand { .. },
+        } => {}
+        ref other => panic!("Unexpected {:?}", other),
+    }
+    assert_eq!(
+        error.to_string(),
+        "Line 2: Task can't have an interval (4) shorter than its demand (5)!"
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn parse_json() {
+    use crate::rta_lib::io::parse_system_json;
+    use crate::rta_lib::server::ServerKind;
+
+    let json = r#"{
+        "servers": [
+            { "capacity": 3, "interval": 10, "kind": "Deferrable", "tasks": [
+                { "demand": 1, "interval": 4 }
+            ] },
+            { "capacity": 2, "interval": 4, "kind": "Periodic", "tasks": [
+                { "demand": 1, "interval": 5 },
+                { "demand": 2, "interval": 8, "offset": 3 }
+            ] }
+        ]
+    }"#;
+
+    let system = parse_system_json(json.as_bytes()).unwrap();
+    let view = system.view();
+    let servers = view.as_system().as_servers();
+
+    assert_eq!(servers.len(), 2);
+    assert_eq!(servers[0].properties.capacity, TimeUnit::from(3));
+    assert_eq!(servers[0].properties.interval, TimeUnit::from(10));
+    assert!(matches!(
+        servers[0].properties.server_type,
+        ServerKind::Deferrable
+    ));
+    assert_eq!(servers[0].as_tasks().len(), 1);
+    assert!(matches!(
+        servers[1].properties.server_type,
+        ServerKind::Periodic
+    ));
+    assert_eq!(servers[1].as_tasks()[1].demand, TimeUnit::from(2));
+    assert_eq!(servers[1].as_tasks()[1].interval, TimeUnit::from(8));
+    assert_eq!(servers[1].as_tasks()[1].offset, TimeUnit::from(3));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn parse_json_errors() {
+    use crate::rta_lib::io::parse_system_json;
+
+    match parse_system_json(r#"{ "servers": [ { "capacity": 3 } ] }"#.as_bytes()) {
+        Err(ParseError::Json(_)) => {}
+        other => panic!("Unexpected {:?}", other),
+    }
+
+    let json = r#"{ "servers": [
+        { "capacity": 1, "interval": 2, "kind": "Periodic", "tasks": [] },
+        { "capacity": 1, "interval": 2, "kind": "Periodic", "tasks": [
+            { "demand": 1, "interval": 4 },
+            { "demand": 5, "interval": 4 }
+        ] }
+    ] }"#;
+
+    let error = parse_system_json(json.as_bytes()).unwrap_err();
+    match error {
+        ParseError::InvalidServerTask {
+            server: 1,
+            task: 1,
+            error: TaskError::IntervalShorterThanDemand { .. },
+        } => {}
+        ref other => panic!("Unexpected {:?}", other),
+    }
+    assert_eq!(
+        error.to_string(),
+        "Server 1, Task 1: Task can't have an interval (4) shorter than its demand (5)!"
+    );
+}
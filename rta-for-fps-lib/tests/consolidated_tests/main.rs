@@ -2,10 +2,15 @@ mod broken_assumption;
 mod curve_tests;
 mod fix_analysis_end;
 mod fixed_actual_execution;
+#[cfg(feature = "std")]
+mod io_tests;
 mod loops;
 mod server_tests;
+#[cfg(feature = "proptest")]
+mod strategy_tests;
 mod system_tests;
 mod task_tests;
+mod time_tests;
 mod window_tests;
 
 use rta_for_fps_lib as rta_lib;
@@ -67,6 +72,71 @@ mod util {
     use crate::rta_lib::curve::curve_types::CurveType;
     use crate::rta_lib::curve::Curve;
     use crate::rta_lib::iterators::CurveIterator;
+    use crate::rta_lib::server::{OwnedServer, ServerProperties};
+    use crate::rta_lib::system::OwnedSystem;
+    use crate::rta_lib::task::Task;
+    use crate::rta_lib::time::TimeUnit;
+
+    /// The System of Example 9.
+    ///
+    /// A Deferrable Server with capacity 3 every 10 serving the Task (1, 4, 0)
+    /// above a Deferrable Server with capacity 2 every 4 serving the Tasks (1, 5, 0) and (2, 8, 0)
+    pub fn example_9() -> OwnedSystem {
+        OwnedSystem::new(vec![
+            OwnedServer {
+                tasks: vec![Task::new(1, 4, 0)],
+                properties: ServerProperties::deferrable(3.into(), 10.into()),
+            },
+            OwnedServer {
+                tasks: vec![Task::new(1, 5, 0), Task::new(2, 8, 0)],
+                properties: ServerProperties::deferrable(2.into(), 4.into()),
+            },
+        ])
+    }
+
+    /// The System of Example 8.
+    ///
+    /// A Deferrable Server with capacity 24 every 24 serving four Tasks
+    /// above a Deferrable Server with capacity 2 every 10 serving three Tasks
+    pub fn example_8() -> OwnedSystem {
+        OwnedSystem::new(vec![
+            OwnedServer {
+                tasks: vec![
+                    Task::new(3, 24, 0),
+                    Task::new(5, 24, 5),
+                    Task::new(5, 24, 12),
+                    Task::new(3, 24, 18),
+                ],
+                properties: ServerProperties::deferrable(24.into(), 24.into()),
+            },
+            OwnedServer {
+                tasks: vec![
+                    Task::new(1, 30, 2),
+                    Task::new(1, 30, 5),
+                    Task::new(2, 30, 10),
+                ],
+                properties: ServerProperties::deferrable(2.into(), 10.into()),
+            },
+        ])
+    }
+
+    /// A System whose second Server is not guaranteed its capacity
+    ///
+    /// The Deferrable Server serving the Task (3, 4, 0) with capacity 3 every 4
+    /// leaves only 1 unit of capacity every 4 units to the Deferrable Server
+    /// serving the Task (2, 4, 0) with capacity 2 every 4
+    pub fn overloaded() -> OwnedSystem {
+        OwnedSystem::new(vec![
+            OwnedServer {
+                tasks: vec![Task::new(3, 4, 0)],
+                properties: ServerProperties::deferrable(3.into(), 4.into()),
+            },
+            OwnedServer {
+                tasks: vec![Task::new(2, 4, 0)],
+                properties: ServerProperties::deferrable(2.into(), 4.into()),
+            },
+        ])
+    }
 
     /// # Panics
     /// When the Curve represents not the same Curve as the the CurveIterator
@@ -75,7 +145,31 @@ mod util {
         expected: &Curve<C>,
         result: impl CurveIterator<CurveKind = C> + Clone,
     ) {
-        if !expected.eq_curve_iterator(result.clone()) {
+        assert_curve_eq_limit(expected, result, None)
+    }
+
+    /// # Panics
+    /// When the Curve represents not the same Curve as the the CurveIterator,
+    /// only comparing up to `limit` if present
+    #[track_caller]
+    pub fn assert_curve_eq_limit<C: CurveType>(
+        expected: &Curve<C>,
+        result: impl CurveIterator<CurveKind = C> + Clone,
+        limit: Option<TimeUnit>,
+    ) {
+        let equal = match limit {
+            None => expected.eq_curve_iterator(result.clone()),
+            Some(limit) => expected.eq_iterator_up_to(result.clone(), limit),
+        };
+
+        if !equal {
+            let result = match limit {
+                None => result.collect_curve::<Curve<_>>(),
+                Some(limit) => result
+                    .take_while_curve(move |window| window.start < limit)
+                    .collect_curve::<Curve<_>>(),
+            };
+
             panic!(
                 "\
             Curves did not match:\n\
@@ -86,8 +180,7 @@ mod util {
             {:#?}\n\
             \n\
             ",
-                expected,
-                result.collect_curve::<Curve<_>>()
+                expected, result
             )
         }
     }
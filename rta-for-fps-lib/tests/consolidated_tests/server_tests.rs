@@ -1,9 +1,11 @@
 use crate::rta_lib::curve::Curve;
+use crate::rta_lib::iterators::cache::CachedCurveIterator;
 use crate::rta_lib::iterators::CurveIterator;
-use crate::rta_lib::server::{Server, ServerKind};
+use crate::rta_lib::server::{Server, ServerKind, ServerProperties};
+use crate::rta_lib::system::System;
 use crate::rta_lib::task::Task;
 use crate::rta_lib::time::TimeUnit;
-use crate::rta_lib::window::Window;
+use crate::rta_lib::window::{Window, WindowEnd};
 
 #[test]
 fn deferrable_server() {
@@ -39,3 +41,334 @@ fn deferrable_server() {
 
     crate::util::assert_curve_eq(&expected_result, result);
 }
+
+#[test]
+fn aggregated_demand_at() {
+    let tasks = &[Task::new(1, 5, 0), Task::new(2, 8, 0), Task::new(1, 10, 3)];
+
+    let server = Server::new(
+        tasks,
+        TimeUnit::from(2),
+        TimeUnit::from(4),
+        ServerKind::Deferrable,
+    );
+
+    for t in [0, 3, 4, 8, 12, 20, 40] {
+        let t = TimeUnit::from(t);
+
+        // all chosen points lie outside of aggregated demand windows,
+        // so the demand of the jobs that arrive before t is exactly
+        // the capacity of the aggregated demand windows that start before t
+        let expected: Curve<_> = server
+            .aggregated_demand_curve_iter()
+            .take_while_curve(|window| window.start < t)
+            .collect_curve();
+
+        assert_eq!(
+            WindowEnd::Finite(server.aggregated_demand_at(t)),
+            expected.capacity(),
+            "t = {:?}",
+            t
+        );
+    }
+}
+
+#[test]
+fn periodic_server_window_ending_on_limit() {
+    // the demand (0,2) ends exactly at the end of the budget of the first group 0 * 10 + 2
+    // and therefore fits completely into the budget of that group
+
+    let tasks = &[Task::new(2, 10, 0)];
+
+    let server = Server::new(
+        tasks,
+        TimeUnit::from(2),
+        TimeUnit::from(10),
+        ServerKind::Periodic,
+    );
+
+    let result = server
+        .constraint_demand_curve_iter()
+        .take_while_curve(|window| window.end <= TimeUnit::from(30));
+
+    let expected_result = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(10, 12),
+            Window::new(20, 22),
+        ])
+    };
+
+    crate::util::assert_curve_eq(&expected_result, result);
+}
+
+#[test]
+fn server_with_tasks() {
+    let tasks = [Task::new(1, 4, 0)];
+    let server = Server::new(&tasks, 2.into(), 5.into(), ServerKind::Deferrable);
+
+    let replaced = {
+        let other_tasks = [Task::new(2, 8, 0), Task::new(1, 10, 3)];
+        let replaced = server.with_tasks(&other_tasks);
+
+        assert!(core::ptr::eq(replaced.as_tasks(), &other_tasks[..]));
+        assert_eq!(replaced.capacity(), server.capacity());
+        assert_eq!(replaced.interval(), server.interval());
+        assert!(matches!(replaced.kind(), ServerKind::Deferrable));
+
+        replaced.aggregated_demand_at(TimeUnit::from(5))
+    };
+
+    assert_eq!(replaced, TimeUnit::from(3));
+    // the original server is unchanged
+    assert!(core::ptr::eq(server.as_tasks(), &tasks[..]));
+}
+
+#[test]
+fn server_worst_case_response_time() {
+    let tasks_s1 = &[Task::new(1, 4, 0)];
+    let tasks_s2 = &[Task::new(1, 8, 0), Task::new(2, 8, 0), Task::new(1, 16, 0)];
+
+    let servers = &[
+        Server::new(tasks_s1, 2.into(), 4.into(), ServerKind::Deferrable),
+        Server::new(tasks_s2, 4.into(), 8.into(), ServerKind::Deferrable),
+        Server::new(&[], 1.into(), 8.into(), ServerKind::Deferrable),
+    ];
+
+    let system = System::new(servers);
+    let up_to = system.system_wide_hyper_period(1);
+
+    let expected = (0..tasks_s2.len())
+        .map(|task_index| Task::original_worst_case_response_time(&system, 1, task_index, up_to))
+        .collect::<Vec<_>>();
+
+    let (task_index, wcrt) =
+        Server::original_worst_case_response_time(&system, 1, up_to).expect("server has tasks");
+
+    assert_eq!(wcrt, *expected.iter().max().expect("server has tasks"));
+    assert_eq!(expected[task_index], wcrt);
+    assert!(expected[..task_index].iter().all(|&other| other < wcrt));
+
+    assert_eq!(
+        Server::fixed_worst_case_response_time(&system, 1, up_to),
+        Some((task_index, wcrt))
+    );
+    assert_eq!(
+        Server::original_worst_case_response_time(&system, 2, up_to),
+        None
+    );
+}
+
+#[test]
+fn cached_constrained_demand() {
+    let tasks = &[Task::new(4, 20, 0), Task::new(3, 10, 5)];
+    let server = Server::deferrable(tasks, TimeUnit::from(2), TimeUnit::from(10));
+
+    let expected: Vec<_> = server
+        .constraint_demand_curve_iter()
+        .take_while_curve(|window| window.start < TimeUnit::from(60))
+        .collect();
+
+    // a clone continues at the position of the original, replaying the shared cache
+    let mut constrained = CachedCurveIterator::new(server.constraint_demand_curve_iter());
+    let first = constrained.next_window();
+    let clone = constrained.clone();
+
+    assert_eq!(first.as_ref(), expected.first());
+
+    let rest: Vec<_> = constrained
+        .take_while_curve(|window| window.start < TimeUnit::from(60))
+        .collect();
+    let cloned_rest: Vec<_> = clone
+        .take_while_curve(|window| window.start < TimeUnit::from(60))
+        .collect();
+
+    assert_eq!(rest, expected[1..]);
+    assert_eq!(cloned_rest, expected[1..]);
+}
+
+#[test]
+fn aggregated_demand_curve_up_to_terminates() {
+    let example_9 = crate::util::example_9();
+    let server = example_9.as_owned_servers()[1].as_server();
+
+    // the unbounded aggregated demand is infinite, collecting the bounded one must terminate
+    let _: Curve<_> = server
+        .aggregated_demand_curve_iter_up_to(TimeUnit::from(40))
+        .collect_curve();
+}
+
+#[test]
+fn background_server() {
+    let high_tasks = &[Task::new(2, 5, 0)];
+    let background_tasks = &[Task::new(3, 10, 0)];
+
+    let servers = &[
+        Server {
+            tasks: high_tasks,
+            properties: ServerProperties::deferrable(TimeUnit::from(2), TimeUnit::from(5)),
+        },
+        Server {
+            tasks: background_tasks,
+            properties: ServerProperties::background(),
+        },
+    ];
+
+    let system = System::new(servers);
+
+    // the background server executes only in the gaps of the higher priority server
+    // without being limited by a capacity
+    let actual_execution = system
+        .original_actual_execution_curve_iter(1)
+        .take_while_curve(|window| window.start < TimeUnit::from(20))
+        .normalize()
+        .collect_curve::<Curve<_>>();
+
+    let expected =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(2, 5), Window::new(12, 15)]) };
+
+    assert_eq!(actual_execution, expected);
+
+    assert_eq!(
+        Task::original_worst_case_response_time(&system, 1, 0, TimeUnit::from(20)),
+        TimeUnit::from(5)
+    );
+    assert_eq!(
+        Task::fixed_worst_case_response_time(&system, 1, 0, TimeUnit::from(20)),
+        TimeUnit::from(5)
+    );
+    assert!(system.schedulability_report().is_schedulable());
+
+    // the gaps in the first interval of the background task provide 6 units,
+    // unaffected by the capacity of zero
+    assert_eq!(
+        Task::max_demand_for_feasibility(&system, 1, 0, TimeUnit::from(20)),
+        TimeUnit::from(6)
+    );
+
+    // the background server without capacity is not critical,
+    // even though the other server leaves 4 units of its budget unused
+    let idle_servers = &[
+        Server {
+            tasks: &[Task::new(1, 10, 0)],
+            properties: ServerProperties::deferrable(TimeUnit::from(5), TimeUnit::from(10)),
+        },
+        Server {
+            tasks: &[],
+            properties: ServerProperties::background(),
+        },
+    ];
+    assert_eq!(
+        System::new(idle_servers).critical_server(TimeUnit::from(20)),
+        Some(0)
+    );
+
+    // the capacity of a Background Server is ignored,
+    // so it is not checked even though the gaps provide less than it each interval
+    let busy_tasks = &[Task::new(4, 5, 0)];
+    let light_tasks = &[Task::new(1, 10, 0)];
+    let servers = &[
+        Server::new(
+            busy_tasks,
+            TimeUnit::from(4),
+            TimeUnit::from(5),
+            ServerKind::Deferrable,
+        ),
+        Server::new(
+            light_tasks,
+            TimeUnit::from(3),
+            TimeUnit::from(10),
+            ServerKind::Background,
+        ),
+    ];
+
+    let system = System::new(servers);
+
+    assert!(system.budget_guarantee_holds(1, TimeUnit::from(20)));
+    assert_eq!(
+        Task::try_fixed_worst_case_response_time(&system, 1, 0, TimeUnit::from(20)),
+        Ok(TimeUnit::from(5))
+    );
+    assert!(system.schedulability_report().is_schedulable());
+}
+
+#[test]
+fn phased_server() {
+    let up_to = TimeUnit::from(40);
+
+    let tasks = &[Task::new(4, 20, 0)];
+    let server = Server::deferrable(tasks, TimeUnit::from(2), TimeUnit::from(10));
+
+    let phased_tasks = &[Task::new(4, 20, 3)];
+    let phased_server = Server {
+        tasks: phased_tasks,
+        properties: ServerProperties::deferrable(TimeUnit::from(2), TimeUnit::from(10))
+            .with_phase(TimeUnit::from(3)),
+    };
+
+    let constrained: Curve<_> = server
+        .constraint_demand_curve_iter()
+        .take_while_curve(|window| window.start < up_to)
+        .collect_curve();
+
+    let phased_constrained: Curve<_> = phased_server
+        .constraint_demand_curve_iter()
+        .take_while_curve(|window| window.start < up_to + TimeUnit::from(3))
+        .collect_curve();
+
+    let expected = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(10, 12),
+            Window::new(20, 22),
+            Window::new(30, 32),
+        ])
+    };
+    assert_eq!(constrained, expected);
+
+    // shifting the task offset and the server phase together shifts the constrained demand
+    let expected_phased = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(3, 5),
+            Window::new(13, 15),
+            Window::new(23, 25),
+            Window::new(33, 35),
+        ])
+    };
+    assert_eq!(phased_constrained, expected_phased);
+
+    let servers = &[phased_server];
+    let system = System::new(servers);
+
+    let phased_execution: Curve<_> = system
+        .original_actual_execution_curve_iter(0)
+        .take_while_curve(|window| window.start < up_to)
+        .collect_curve();
+
+    let expected_execution = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(3, 5),
+            Window::new(13, 15),
+            Window::new(23, 25),
+            Window::new(33, 35),
+        ])
+    };
+    assert_eq!(phased_execution, expected_execution);
+
+    // demand before the first replenishment at 3 has to wait for it,
+    // as the shortened first group has no budget
+    let early_server = Server {
+        tasks,
+        properties: ServerProperties::deferrable(TimeUnit::from(2), TimeUnit::from(10))
+            .with_phase(TimeUnit::from(3)),
+    };
+
+    let early_constrained: Curve<_> = early_server
+        .constraint_demand_curve_iter()
+        .take_while_curve(|window| window.start < TimeUnit::from(20))
+        .collect_curve();
+
+    let expected_early =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(3, 5), Window::new(13, 15)]) };
+    assert_eq!(early_constrained, expected_early);
+}
@@ -0,0 +1,71 @@
+use crate::rta_lib::curve::curve_types::UnspecifiedCurve;
+use crate::rta_lib::curve::Curve;
+use crate::rta_lib::strategy::{curve_strategy, window_strategy};
+use crate::rta_lib::time::TimeUnit;
+use crate::rta_lib::window::{Supply, WindowEnd};
+use proptest::prelude::*;
+
+/// The end of the time range generated Curves are contained in
+const MAX_TIME: usize = 100;
+
+/// Strategy generating Supply Curves within `[0, MAX_TIME]`
+fn supply_curve() -> impl Strategy<Value = Curve<UnspecifiedCurve<Supply>>> {
+    curve_strategy(8, TimeUnit::from(MAX_TIME))
+}
+
+proptest! {
+    #[test]
+    fn generated_windows_are_valid(window in window_strategy::<Supply>(TimeUnit::from(MAX_TIME))) {
+        prop_assert!(!window.is_empty());
+        prop_assert!(window.end <= WindowEnd::Finite(TimeUnit::from(MAX_TIME)));
+    }
+
+    #[test]
+    fn generated_curves_are_valid(curve in supply_curve()) {
+        for window in curve.as_windows() {
+            prop_assert!(!window.is_empty());
+        }
+        for pair in curve.as_windows().windows(2) {
+            prop_assert!(pair[0].end < pair[1].start);
+        }
+    }
+
+    #[test]
+    fn generated_curves_fit_short_time_ranges(
+        curve in curve_strategy::<UnspecifiedCurve<Supply>>(8, TimeUnit::from(3))
+    ) {
+        // [0, 3] has only four points for the windows
+        prop_assert!(curve.as_windows().len() <= 2);
+        prop_assert!(curve.capacity() <= WindowEnd::Finite(TimeUnit::from(3)));
+    }
+
+    #[test]
+    fn complement_capacity(curve in supply_curve()) {
+        let limit = TimeUnit::from(MAX_TIME);
+        let complement: Curve<UnspecifiedCurve<Supply>> = curve.invert_up_to(limit);
+
+        prop_assert_eq!(curve.capacity() + complement.capacity(), WindowEnd::Finite(limit));
+    }
+
+    #[test]
+    fn union_intersection_inclusion_exclusion(a in supply_curve(), b in supply_curve()) {
+        let limit = TimeUnit::from(MAX_TIME);
+
+        let union = a.union(&b);
+
+        // A ∩ B as the complement of the union of the complements
+        let a_complement: Curve<UnspecifiedCurve<Supply>> = a.invert_up_to(limit);
+        let b_complement = b.invert_up_to(limit);
+        let intersection: Curve<UnspecifiedCurve<Supply>> =
+            a_complement.union(&b_complement).invert_up_to(limit);
+
+        prop_assert_eq!(
+            a.capacity() + b.capacity(),
+            union.capacity() + intersection.capacity()
+        );
+
+        for window in intersection.as_windows() {
+            prop_assert!(union.as_windows().iter().any(|outer| outer.start <= window.start && window.end <= outer.end));
+        }
+    }
+}
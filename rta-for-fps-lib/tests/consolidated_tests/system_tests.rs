@@ -1,10 +1,13 @@
 use crate::rta_lib::curve::Curve;
+use crate::rta_lib::iterators::curve::CapacityError;
 use crate::rta_lib::iterators::CurveIterator;
-use crate::rta_lib::server::{Server, ServerKind};
-use crate::rta_lib::system::System;
+use crate::rta_lib::server::{Server, ServerIdle, ServerKind, ServerProperties};
+use crate::rta_lib::system::{
+    OrderError, OverlapReport, OwnedSystem, System, TaskResult, Utilization,
+};
 use crate::rta_lib::task::Task;
 use crate::rta_lib::time::TimeUnit;
-use crate::rta_lib::window::Window;
+use crate::rta_lib::window::{Window, WindowEnd};
 
 #[test]
 fn unconstrained_curve() {
@@ -29,8 +32,7 @@ fn unconstrained_curve() {
         .iter()
         .map(|server| server.constraint_demand_curve_iter());
 
-    let aggregated_result = System::aggregated_higher_priority_demand_curve_iter(csdi)
-        .take_while_curve(|window| window.end <= up_to);
+    let aggregated_result = System::aggregated_higher_priority_demand_curve_iter(csdi);
 
     let expected_aggregated_result = unsafe {
         Curve::from_windows_unchecked(vec![
@@ -41,7 +43,7 @@ fn unconstrained_curve() {
         ])
     };
 
-    crate::util::assert_curve_eq(&expected_aggregated_result, aggregated_result);
+    crate::util::assert_curve_eq_limit(&expected_aggregated_result, aggregated_result, Some(up_to));
 
     let unconstrained_result = system
         .original_unconstrained_server_execution_curve_iter(1)
@@ -154,6 +156,137 @@ fn executive_curve() {
     crate::util::assert_curve_eq(&expected_c_execution, c_execution_result);
 }
 
+#[test]
+fn total_constrained_demand_curve() {
+    let example_8 = crate::util::example_8();
+    let view = example_8.view();
+    let system = view.as_system();
+
+    let up_to = TimeUnit::from(24);
+
+    // Total constrained demand of both servers
+
+    let total_demand: Curve<_> = system
+        .total_constrained_demand_curve_iter()
+        .take_while_curve(|window| window.end <= up_to)
+        .collect_curve();
+
+    let expected_total_demand =
+        unsafe { Curve::from_windows_unchecked(vec![Window::new(0, 4), Window::new(5, 21)]) };
+
+    assert_eq!(total_demand, expected_total_demand);
+}
+
+#[test]
+fn idle_curve() {
+    let example_8 = crate::util::example_8();
+    let view = example_8.view();
+    let system = view.as_system();
+
+    let up_to = TimeUnit::from(24);
+
+    // Idle curve, the gaps in the fixed actual execution
+
+    let idle: Curve<ServerIdle> = system.idle_curve_iter(1, up_to).collect_curve();
+
+    let fixed_execution: Curve<_> = system
+        .fixed_actual_execution_curve_iter(1)
+        .normalize()
+        .take_while_curve(|window| window.start < up_to)
+        .collect_curve();
+
+    assert_eq!(idle, fixed_execution.invert_up_to(up_to));
+
+    let expected_idle = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 3),
+            Window::new(4, 10),
+            Window::new(12, 21),
+            Window::new(22, 24),
+        ])
+    };
+
+    assert_eq!(idle, expected_idle);
+}
+
+#[test]
+fn busy_periods() {
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+
+    assert_eq!(
+        system.busy_periods(1, TimeUnit::from(16)),
+        vec![
+            Window::new(1, 3),
+            Window::new(5, 7),
+            Window::new(9, 11),
+            Window::new(13, 14),
+            Window::new(15, 16),
+        ]
+    );
+    // the last busy period is clipped
+    assert_eq!(
+        system.busy_periods(1, TimeUnit::from(10)),
+        vec![Window::new(1, 3), Window::new(5, 7), Window::new(9, 10)]
+    );
+    assert_eq!(
+        system.longest_busy_period(1, TimeUnit::from(16)),
+        TimeUnit::from(2)
+    );
+    assert_eq!(system.longest_busy_period(1, TimeUnit::ONE), TimeUnit::ZERO);
+
+    // execution split at the replenishment of the budget forms a single busy period
+    let tasks = &[Task::new(6, 8, 0)];
+    let servers = &[Server::new(
+        tasks,
+        TimeUnit::from(4),
+        TimeUnit::from(4),
+        ServerKind::Deferrable,
+    )];
+    let system = System::new(servers);
+
+    assert_eq!(
+        system.busy_periods(0, TimeUnit::from(16)),
+        vec![Window::new(0, 6), Window::new(8, 14)]
+    );
+    assert_eq!(
+        system.longest_busy_period(0, TimeUnit::from(16)),
+        TimeUnit::from(6)
+    );
+}
+
+#[test]
+fn can_admit() {
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+
+    let arrival_before = TimeUnit::from(40);
+
+    let light_tasks = &[Task::new(1, 10, 0)];
+    let light = Server::new(
+        light_tasks,
+        TimeUnit::from(1),
+        TimeUnit::from(10),
+        ServerKind::Deferrable,
+    );
+    assert!(system.can_admit(&light, arrival_before));
+
+    // the tasks demand more often than the server is replenished
+    let urgent_tasks = &[Task::new(2, 4, 0)];
+    let urgent = Server::new(
+        urgent_tasks,
+        TimeUnit::from(2),
+        TimeUnit::from(10),
+        ServerKind::Deferrable,
+    );
+    assert!(!system.can_admit(&urgent, arrival_before));
+
+    // the system itself is unchanged
+    assert_eq!(system.as_servers().len(), 2);
+}
+
 #[test]
 fn response_time() {
     // Example 9.
@@ -348,3 +481,534 @@ fn comparison() {
     let wcrt = Task::original_worst_case_response_time(&system, 1, 0, swh);
     assert_eq!(wcrt, expected_response_time);
 }
+
+#[test]
+fn try_actual_execution() {
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+
+    // with sufficient capacity the result matches the panicking version
+    let expected: Vec<_> = system
+        .fixed_actual_execution_curve_iter(1)
+        .into_iterator()
+        .take(10)
+        .collect();
+
+    let mut result = Vec::new();
+    let mut iter = system.try_actual_execution_curve_iter(1);
+    while result.len() < 10 {
+        match iter.next_window() {
+            Ok(Some(window)) => result.push(window),
+            other => panic!("Unexpected {:?}", other),
+        }
+    }
+
+    assert_eq!(result, expected);
+
+    // the higher priority server leaves only 1 unit of capacity every 4 units
+    let overloaded = crate::util::overloaded();
+    let view = overloaded.view();
+    let system = view.as_system();
+
+    let mut iter = system.try_actual_execution_curve_iter(1);
+
+    let error = loop {
+        match iter.next_window() {
+            Ok(Some(_)) => {}
+            Ok(None) => panic!("Expected the capacity check to fail"),
+            Err(error) => break error,
+        }
+    };
+
+    assert_eq!(
+        error,
+        CapacityError {
+            group: 0,
+            expected: TimeUnit::from(2),
+            observed: TimeUnit::from(1),
+        }
+    );
+
+    // the error is repeated without advancing past the failed group
+    for _ in 0..100 {
+        assert_eq!(iter.next_window(), Err(error));
+    }
+
+    // the WCRT analysis stops at the same error
+    assert_eq!(
+        Task::try_fixed_worst_case_response_time(&system, 1, 0, TimeUnit::from(40)),
+        Err(error)
+    );
+}
+
+#[test]
+fn critical_server() {
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+
+    // server 1 uses all of its budget in [0,10)
+    // server 2 uses all of its budget in [0,4)
+    // with equal slack the higher priority server is chosen
+    assert_eq!(system.critical_server(TimeUnit::from(20)), Some(0));
+
+    // server 1 only uses 1 of its budget in [0,4)
+    assert_eq!(system.critical_server(TimeUnit::from(4)), Some(1));
+
+    assert_eq!(System::new(&[]).critical_server(TimeUnit::from(20)), None);
+
+    // server 2 is not guaranteed its capacity and is skipped
+    let overloaded = crate::util::overloaded();
+    let view = overloaded.view();
+    let system = view.as_system();
+
+    assert_eq!(system.critical_server(TimeUnit::from(8)), Some(0));
+}
+
+#[test]
+fn per_server_bounds() {
+    let tasks_s1 = &[Task::new(1, 4, 3)];
+    let tasks_s2 = &[Task::new(1, 5, 0), Task::new(2, 8, 7)];
+    let tasks_s3 = &[Task::new(1, 20, 11)];
+
+    let servers = &[
+        Server::new(tasks_s1, 3.into(), 10.into(), ServerKind::Deferrable),
+        Server::new(tasks_s2, 2.into(), 4.into(), ServerKind::Deferrable),
+        Server::new(tasks_s3, 1.into(), 30.into(), ServerKind::Periodic),
+    ];
+
+    let system = System::new(servers);
+
+    let bounds: Vec<_> = system.per_server_bounds().collect();
+
+    assert_eq!(bounds.len(), servers.len());
+
+    for (expected_index, &(server_index, swh, analysis_end)) in bounds.iter().enumerate() {
+        assert_eq!(server_index, expected_index);
+        assert_eq!(swh, system.system_wide_hyper_period(server_index));
+        assert_eq!(analysis_end, system.analysis_end(server_index));
+        assert!(swh <= analysis_end);
+    }
+
+    assert_eq!(bounds[2], (2, TimeUnit::from(120), TimeUnit::from(131)));
+}
+
+#[test]
+fn available_server_execution() {
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+
+    // server 1 executes in (0,1), (4,5), (8,9), ...
+    let available: Vec<_> = system
+        .available_server_execution_curve_iter(1)
+        .into_iterator()
+        .take(3)
+        .collect();
+
+    assert_eq!(
+        available,
+        vec![Window::new(1, 4), Window::new(5, 8), Window::new(9, 12)]
+    );
+
+    // the highest priority server has all execution available
+    let available: Vec<_> = system
+        .available_server_execution_curve_iter(0)
+        .into_iterator()
+        .collect();
+
+    assert_eq!(available, vec![Window::new(0, WindowEnd::Infinite)]);
+}
+
+#[test]
+fn interference() {
+    let tasks_s1 = &[Task::new(1, 8, 2)];
+    let tasks_s2 = &[Task::new(2, 4, 2)];
+    let tasks_s3 = &[Task::new(1, 4, 2)];
+
+    let servers = &[
+        Server::deferrable(tasks_s1, 1.into(), 8.into()),
+        Server::deferrable(tasks_s2, 2.into(), 4.into()),
+        Server::deferrable(tasks_s3, 1.into(), 4.into()),
+    ];
+    let system = System::new(servers);
+
+    let original: Vec<_> = system
+        .original_interference_curve_iter(2)
+        .normalize()
+        .take_while_curve(|window| window.end <= TimeUnit::from(18))
+        .collect();
+
+    // the original algorithm assumes the second server executes in (7,8),
+    // but its budget is exhausted, so it only continues in (8,9) after replenishment
+    assert_eq!(
+        original,
+        vec![
+            Window::new(2, 5),
+            Window::new(6, 8),
+            Window::new(10, 13),
+            Window::new(14, 16),
+        ]
+    );
+
+    let fixed: Vec<_> = system
+        .fixed_interference_curve_iter(2)
+        .normalize()
+        .take_while_curve(|window| window.end <= TimeUnit::from(18))
+        .collect();
+
+    assert_eq!(
+        fixed,
+        vec![
+            Window::new(2, 5),
+            Window::new(6, 7),
+            Window::new(8, 9),
+            Window::new(10, 13),
+            Window::new(14, 15),
+            Window::new(16, 17),
+        ]
+    );
+
+    // the highest priority server experiences no interference
+    assert_eq!(
+        system.original_interference_curve_iter(0).next_window(),
+        None
+    );
+    assert_eq!(system.fixed_interference_curve_iter(0).next_window(), None);
+}
+
+#[test]
+fn schedulability_report() {
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+    let report = system.schedulability_report();
+
+    assert_eq!(
+        report.utilization,
+        Utilization {
+            capacity: 16.into(),
+            interval: 20.into(),
+        }
+    );
+    assert!(!report.utilization.exceeds_one());
+
+    assert_eq!(report.servers.len(), 2);
+    for (server_index, server) in report.servers.iter().enumerate() {
+        assert_eq!(server.analysis_end, system.analysis_end(server_index));
+        assert_eq!(
+            server.tasks.len(),
+            system.as_servers()[server_index].as_tasks().len()
+        );
+
+        for (task_index, result) in server.tasks.iter().enumerate() {
+            let wcrt = Task::fixed_worst_case_response_time(
+                &system,
+                server_index,
+                task_index,
+                server.analysis_end,
+            );
+            let deadline = system.as_servers()[server_index].as_tasks()[task_index].interval;
+            assert_eq!(
+                *result,
+                TaskResult::Analysed {
+                    wcrt,
+                    deadline,
+                    schedulable: wcrt <= deadline,
+                }
+            );
+        }
+    }
+
+    // the higher priority server leaves only 1 unit of capacity every 4 units
+    let overloaded = crate::util::overloaded();
+    let view = overloaded.view();
+    let system = view.as_system();
+    let report = system.schedulability_report();
+
+    assert!(report.utilization.exceeds_one());
+    assert!(!report.is_schedulable());
+    assert!(matches!(
+        report.servers[0].tasks[0],
+        TaskResult::Analysed {
+            schedulable: true,
+            ..
+        }
+    ));
+    assert!(matches!(
+        report.servers[1].tasks[0],
+        TaskResult::AnalysisFailed(CapacityError { group: 0, .. })
+    ));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn schedulability_report_serialize() {
+    let report = crate::util::overloaded().schedulability_report();
+
+    assert_eq!(
+        serde_json::to_string(&report).unwrap(),
+        concat!(
+            r#"{"utilization":{"capacity":5,"interval":4},"servers":["#,
+            r#"{"analysis_end":4,"tasks":[{"Analysed":{"wcrt":3,"deadline":4,"schedulable":true}}]},"#,
+            r#"{"analysis_end":4,"tasks":[{"AnalysisFailed":{"group":0,"expected":2,"observed":1}}]}]}"#
+        )
+    );
+}
+
+#[test]
+fn budget_guarantee_holds() {
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+    let up_to = system.analysis_end(1);
+
+    assert!(system.budget_guarantee_holds(0, up_to));
+    assert!(system.budget_guarantee_holds(1, up_to));
+
+    // the higher priority server leaves only 1 unit of capacity every 4 units
+    let overloaded = crate::util::overloaded();
+    let view = overloaded.view();
+    let system = view.as_system();
+
+    assert!(system.budget_guarantee_holds(0, 16.into()));
+    assert!(!system.budget_guarantee_holds(1, 16.into()));
+    assert!(!system.budget_guarantee_holds(1, 1.into()));
+    assert!(system.budget_guarantee_holds(1, 0.into()));
+}
+
+#[test]
+fn map_server() {
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+    let up_to = system.analysis_end(1);
+
+    // an unmodified owned copy analyses the same
+    let owned = OwnedSystem::from(&system);
+    assert_eq!(
+        Task::fixed_worst_case_response_time(&owned.view().as_system(), 1, 1, up_to),
+        Task::fixed_worst_case_response_time(&system, 1, 1, up_to)
+    );
+    assert_eq!(owned.analysis_end(1), up_to);
+    assert_eq!(
+        owned.fixed_worst_case_response_time(1, 1, up_to),
+        Task::fixed_worst_case_response_time(&system, 1, 1, up_to)
+    );
+    assert_eq!(
+        owned.original_worst_case_response_time(1, 1, up_to),
+        Task::original_worst_case_response_time(&system, 1, 1, up_to)
+    );
+    assert_eq!(
+        owned.schedulability_report(),
+        system.schedulability_report()
+    );
+
+    // a moved or cloned OwnedSystem still analyses the same
+    let moved = vec![owned];
+    let cloned = moved[0].clone();
+    drop(moved);
+    assert_eq!(
+        cloned.fixed_worst_case_response_time(1, 1, up_to),
+        Task::fixed_worst_case_response_time(&system, 1, 1, up_to)
+    );
+    assert_eq!(
+        cloned.view().as_system().as_servers()[1].as_tasks().len(),
+        2
+    );
+
+    // the server slice outlives a temporary System
+    let borrowed = System::new(system.as_servers()).as_servers();
+    assert_eq!(borrowed.len(), 2);
+
+    for capacity in 1..=3 {
+        let swept = system.map_server(0, |properties| ServerProperties {
+            capacity: capacity.into(),
+            ..properties
+        });
+
+        let servers = system.as_servers();
+        let expected_servers = &[
+            Server {
+                properties: ServerProperties {
+                    capacity: capacity.into(),
+                    ..servers[0].properties
+                },
+                ..servers[0].clone()
+            },
+            servers[1].clone(),
+        ];
+        let expected = System::new(expected_servers);
+
+        assert_eq!(
+            Task::fixed_worst_case_response_time(&swept.view().as_system(), 1, 1, up_to),
+            Task::fixed_worst_case_response_time(&expected, 1, 1, up_to)
+        );
+    }
+
+    // the original system is unchanged
+    assert_eq!(
+        system.as_servers()[0].properties.capacity,
+        TimeUnit::from(3)
+    );
+}
+
+#[test]
+fn response_time_matrix() {
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+
+    let matrix =
+        system.response_time_matrix(|server_index| system.system_wide_hyper_period(server_index));
+
+    assert_eq!(matrix.len(), system.as_servers().len());
+
+    for (server_index, row) in matrix.iter().enumerate() {
+        let swh = system.system_wide_hyper_period(server_index);
+
+        let expected: Vec<_> = (0..system.as_servers()[server_index].as_tasks().len())
+            .map(|task_index| {
+                Ok(Task::original_worst_case_response_time(
+                    &system,
+                    server_index,
+                    task_index,
+                    swh,
+                ))
+            })
+            .collect();
+
+        assert_eq!(row, &expected);
+    }
+
+    // Example 9.
+    assert_eq!(matrix[1][0], Ok(TimeUnit::from(3)));
+
+    // the second server is not guaranteed its capacity,
+    // which is reported instead of panicking
+    let overloaded = crate::util::overloaded();
+    let view = overloaded.view();
+    let system = view.as_system();
+
+    let matrix = system.response_time_matrix(|_| TimeUnit::from(40));
+
+    assert!(matrix[0].iter().all(Result::is_ok));
+    assert!(matrix[1].iter().all(Result::is_err));
+}
+
+#[test]
+fn monotonic_order() {
+    let tasks = &[Task::new(1, 10, 0)];
+
+    let servers = &[
+        Server::new(tasks, 2.into(), 10.into(), ServerKind::Deferrable),
+        Server::new(tasks, 1.into(), 4.into(), ServerKind::Periodic),
+        Server::new(tasks, 3.into(), 10.into(), ServerKind::Deferrable),
+        Server::new(tasks, 1.into(), 6.into(), ServerKind::Deferrable),
+    ];
+
+    assert_eq!(System::rate_monotonic_order(servers), vec![1, 3, 0, 2]);
+    assert_eq!(System::deadline_monotonic_order(servers), vec![1, 3, 0, 2]);
+    assert_eq!(System::rate_monotonic_order(&[]), Vec::<usize>::new());
+
+    let system = System::new(servers)
+        .reorder(&System::rate_monotonic_order(servers))
+        .unwrap();
+
+    let intervals: Vec<_> = system
+        .as_owned_servers()
+        .iter()
+        .map(|server| server.as_server().interval())
+        .collect();
+    assert_eq!(
+        intervals,
+        vec![
+            TimeUnit::from(4),
+            TimeUnit::from(6),
+            TimeUnit::from(10),
+            TimeUnit::from(10)
+        ]
+    );
+    assert_eq!(
+        system.as_owned_servers()[2].as_server().capacity(),
+        TimeUnit::from(2)
+    );
+}
+
+#[test]
+fn reorder_servers() {
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+
+    let swapped = system.reorder(&[1, 0]).unwrap();
+
+    let servers = system.as_servers();
+    let expected_servers = &[servers[1].clone(), servers[0].clone()];
+    let expected = System::new(expected_servers);
+
+    assert_eq!(
+        swapped.as_owned_servers()[0].as_server().interval(),
+        TimeUnit::from(4)
+    );
+    assert_eq!(
+        swapped.as_owned_servers()[1].as_server().interval(),
+        TimeUnit::from(10)
+    );
+    assert_eq!(
+        swapped.schedulability_report(),
+        expected.schedulability_report()
+    );
+
+    assert_eq!(
+        system.reorder(&[0]).unwrap_err(),
+        OrderError::WrongLength {
+            expected: 2,
+            actual: 1
+        }
+    );
+    assert_eq!(
+        system.reorder(&[0, 2]).unwrap_err(),
+        OrderError::IndexOutOfRange(2)
+    );
+    assert_eq!(
+        system.reorder(&[1, 1]).unwrap_err(),
+        OrderError::DuplicateIndex(1)
+    );
+}
+
+#[test]
+fn check_no_execution_overlap() {
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+
+    assert_eq!(system.check_no_execution_overlap(40.into()), Ok(()));
+
+    // the original algorithm lets the lowest priority server execute in (8, 9)
+    // while the second server executes
+    let tasks_s1 = &[Task::new(1, 8, 2)];
+    let tasks_s2 = &[Task::new(2, 4, 2)];
+    let tasks_s3 = &[Task::new(1, 4, 2)];
+
+    let servers = &[
+        Server::deferrable(tasks_s1, 1.into(), 8.into()),
+        Server::deferrable(tasks_s2, 2.into(), 4.into()),
+        Server::deferrable(tasks_s3, 1.into(), 4.into()),
+    ];
+    let system = System::new(servers);
+
+    assert_eq!(
+        system.check_no_execution_overlap(18.into()),
+        Err(OverlapReport {
+            higher_priority_server: 1,
+            lower_priority_server: 2,
+            higher_priority_window: Window::new(8, 9),
+            lower_priority_window: Window::new(8, 9),
+        })
+    );
+
+    // the overlap is not reported before it starts
+    assert_eq!(system.check_no_execution_overlap(8.into()), Ok(()));
+}
@@ -1,10 +1,32 @@
 use crate::rta_lib::curve::Curve;
 use crate::rta_lib::iterators::curve::AggregationIterator;
 use crate::rta_lib::iterators::CurveIterator;
+use crate::rta_lib::server::{Server, ServerKind};
+use crate::rta_lib::system::System;
 use crate::rta_lib::task::curve_types::TaskDemand;
-use crate::rta_lib::task::Task;
+use crate::rta_lib::task::{Task, TaskError};
 use crate::rta_lib::time::TimeUnit;
-use crate::rta_lib::window::Window;
+use crate::rta_lib::window::{Supply, Window};
+use std::cell::Cell;
+
+#[test]
+fn supply_curve() {
+    let task = Task::new(2, 5, 1);
+
+    let supply: Vec<Window<Supply>> = task
+        .supply_curve_iter()
+        .take_while_curve(|window| window.end <= TimeUnit::from(12))
+        .collect();
+
+    let demand: Vec<Window<Supply>> = task
+        .bounded_demand_iter(TimeUnit::from(12))
+        .into_iterator()
+        .map(Window::reclassify)
+        .collect();
+
+    assert_eq!(supply, vec![Window::new(1, 3), Window::new(6, 8)]);
+    assert_eq!(supply, demand);
+}
 
 #[test]
 fn demand_curve() {
@@ -15,7 +37,7 @@ fn demand_curve() {
 
     let up_to = TimeUnit::from(18);
 
-    let c_2 = t_2.into_iter().take_while(|window| window.end <= up_to);
+    let c_2 = t_2.bounded_demand_iter(up_to);
 
     let expected_c_2 = unsafe {
         Curve::from_windows_unchecked(vec![
@@ -28,7 +50,7 @@ fn demand_curve() {
 
     crate::util::assert_curve_eq(&expected_c_2, c_2);
 
-    let c_3 = t_3.into_iter().take_while(|window| window.end <= up_to);
+    let c_3 = t_3.bounded_demand_iter(up_to);
 
     let expected_c_3 = unsafe {
         Curve::from_windows_unchecked(vec![
@@ -50,11 +72,9 @@ fn aggregated_demand_curve() {
 
     let up_to = TimeUnit::from(18);
 
-    let f = |window: &Window<_>| window.end <= up_to;
+    let t2_demand = t_2.bounded_demand_iter(up_to);
 
-    let t2_demand = t_2.into_iter().take_while(f);
-
-    let t3_demand = t_3.into_iter().take_while(f);
+    let t3_demand = t_3.bounded_demand_iter(up_to);
 
     let result: Curve<TaskDemand> =
         AggregationIterator::new(vec![t2_demand, t3_demand]).collect_curve();
@@ -70,3 +90,414 @@ fn aggregated_demand_curve() {
 
     assert_eq!(result, expected_result);
 }
+
+#[test]
+fn count_demand_windows() {
+    let t_1 = Task::new(1, 5, 0);
+    let t_2 = Task::new(2, 8, 0);
+
+    assert_eq!(t_1.into_iter().count_windows_up_to(TimeUnit::from(10)), 2);
+    assert_eq!(t_1.into_iter().count_windows_up_to(TimeUnit::from(11)), 3);
+
+    // the window (8,10) straddles the limit and is counted once
+    assert_eq!(t_2.into_iter().count_windows_up_to(TimeUnit::from(9)), 2);
+}
+
+#[test]
+fn sum_demand_length_while() {
+    let task = Task::new(2, 5, 0);
+
+    // windows (0,2), (5,7) and (10,12) end before 13
+    assert_eq!(
+        task.into_iter()
+            .sum_length_while(|window| window.end <= TimeUnit::from(13)),
+        TimeUnit::from(6)
+    );
+
+    assert_eq!(task.into_iter().sum_length_while(|_| false), TimeUnit::ZERO);
+}
+
+#[test]
+fn max_demand_for_feasibility() {
+    let hp_tasks = &[Task::new(2, 4, 0)];
+    let tasks = &[Task::new(1, 6, 0)];
+
+    let servers = &[
+        Server::new(hp_tasks, 2.into(), 4.into(), ServerKind::Deferrable),
+        Server::new(tasks, 2.into(), 4.into(), ServerKind::Deferrable),
+    ];
+
+    let system = System::new(servers);
+    let end = system.analysis_end(1);
+
+    // a demand of 3 would finish the first job at 7, past its interval of 6
+    let max_demand = Task::max_demand_for_feasibility(&system, 1, 0, end);
+    assert_eq!(max_demand, TimeUnit::from(2));
+
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+    let swh = system.system_wide_hyper_period(1);
+
+    // a demand of 2 would exceed the servers capacity of 20 every 40
+    let max_demand = Task::max_demand_for_feasibility(&system, 1, 0, swh);
+    assert_eq!(max_demand, TimeUnit::from(1));
+}
+
+#[test]
+fn inspect_demand_windows() {
+    let task = Task::new(1, 5, 0);
+
+    let mut inspected = vec![];
+
+    let result: Curve<_> = task
+        .into_iter()
+        .inspect_windows(|window| inspected.push(window.clone()))
+        .take_while_curve(|window| window.end <= TimeUnit::from(12))
+        .collect_curve();
+
+    let expected = vec![Window::new(0, 1), Window::new(5, 6), Window::new(10, 11)];
+
+    assert_eq!(result.as_windows(), expected.as_slice());
+
+    // the first window past the limit has been inspected as well before being rejected
+    assert_eq!(inspected.len(), 4);
+    assert_eq!(inspected[..3], expected[..]);
+}
+
+#[test]
+fn take_while_normalize_stops_polling() {
+    // adjacent windows (0,2), (2,4), (4,6), ... which normalize joins
+    let task = Task::new(2, 2, 0);
+
+    let polled = Cell::new(0);
+
+    let mut normalized = task
+        .into_iter()
+        .inspect_windows(|_| polled.set(polled.get() + 1))
+        .take_while_curve(|window| window.end <= TimeUnit::from(6))
+        .normalize();
+
+    assert_eq!(normalized.next_window(), Some(Window::new(0, 6)));
+    assert_eq!(normalized.next_window(), None);
+    assert_eq!(normalized.next_window(), None);
+
+    // the first window failing the predicate is polled, but nothing after it
+    assert_eq!(polled.get(), 4);
+}
+
+#[test]
+fn busy_period() {
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+
+    // server 2 executes in (1,3), (5,7), ...
+    // task 1 is done at 2
+    assert_eq!(Task::busy_period(&system, 1, 0), TimeUnit::from(2));
+
+    // the 3 units of demand arriving at 0 are served by 6,
+    // but the second job of task 1 arriving at 5 is only served at 7
+    assert_eq!(Task::busy_period(&system, 1, 1), TimeUnit::from(7));
+}
+
+#[test]
+fn negative_phase() {
+    // first job arrived at -1, its whole demand is placed at the origin
+    let task = Task::with_phase(2, 5, -1);
+
+    assert_eq!(task.offset, TimeUnit::ZERO);
+    assert_eq!(task.pre_start, TimeUnit::ONE);
+    assert_eq!(task.job_arrival(0), TimeUnit::ZERO);
+    assert_eq!(task.job_arrival(1), TimeUnit::from(4));
+
+    let demand: Vec<_> = task.into_iter().take(3).collect();
+    assert_eq!(
+        demand,
+        vec![Window::new(0, 2), Window::new(4, 6), Window::new(9, 11)]
+    );
+
+    assert_eq!(
+        task.released_demand_before(TimeUnit::from(4)),
+        TimeUnit::from(2)
+    );
+    assert_eq!(
+        task.released_demand_before(TimeUnit::from(5)),
+        TimeUnit::from(4)
+    );
+
+    // jobs arriving an interval or more before the origin are dropped, -7 ≡ -2
+    let task = Task::with_phase(2, 5, -7);
+    let demand: Vec<_> = task.into_iter().take(2).collect();
+    assert_eq!(demand, vec![Window::new(0, 2), Window::new(3, 5)]);
+
+    // positive phases are offsets
+    let task = Task::with_phase(2, 5, 3);
+    assert_eq!(task.offset, TimeUnit::from(3));
+    assert_eq!(task.pre_start, TimeUnit::ZERO);
+
+    let tasks = &[Task::with_phase(2, 5, -1)];
+    let servers = &[Server::new(
+        tasks,
+        5.into(),
+        5.into(),
+        ServerKind::Deferrable,
+    )];
+    let system = System::new(servers);
+
+    // the first job arrives at -1 and finishes at 2, the second job arrives at 4 and finishes at 6
+    assert_eq!(
+        Task::fixed_worst_case_response_time(&system, 0, 0, TimeUnit::from(20)),
+        TimeUnit::from(3)
+    );
+}
+
+#[test]
+fn from_utilization() {
+    let interval = TimeUnit::from(10);
+    let offset = TimeUnit::from(2);
+
+    let task = Task::from_utilization(0.25, interval, offset);
+    assert_eq!(task.demand, TimeUnit::from(2));
+    assert_eq!(task.interval, interval);
+    assert_eq!(task.offset, offset);
+
+    // clamped to at least one unit of demand
+    assert_eq!(
+        Task::from_utilization(0.01, interval, offset).demand,
+        TimeUnit::ONE
+    );
+    assert_eq!(
+        Task::from_utilization(1.0, interval, offset).demand,
+        interval
+    );
+
+    for utilization in &[0.0, -0.5, 1.5, f64::NAN] {
+        assert_eq!(
+            Task::try_from_utilization(*utilization, interval, offset).err(),
+            Some(TaskError::UtilizationOutOfRange)
+        );
+    }
+
+    assert_eq!(
+        Task::try_from_utilization(0.5, TimeUnit::ZERO, offset).err(),
+        Some(TaskError::ZeroInterval)
+    );
+}
+
+#[test]
+fn preemption_count() {
+    let tasks = &[Task::new(3, 10, 0)];
+    let servers = &[Server::new(
+        tasks,
+        TimeUnit::from(2),
+        TimeUnit::from(4),
+        ServerKind::Deferrable,
+    )];
+    let system = System::new(servers);
+
+    let execution: Curve<_> = Task::fixed_actual_execution_curve_iter(&system, 0, 0)
+        .normalize()
+        .take_while_curve(|window| window.end <= TimeUnit::from(20))
+        .collect_curve();
+
+    // the first job is preempted once the server's budget is exhausted,
+    // the second job executes at the end of one and the start of the next interval without a gap
+    let expected = unsafe {
+        Curve::from_windows_unchecked(vec![
+            Window::new(0, 2),
+            Window::new(4, 5),
+            Window::new(10, 13),
+        ])
+    };
+    assert_eq!(execution, expected);
+
+    assert_eq!(Task::preemption_count(&system, 0, 0, TimeUnit::from(3)), 0);
+    assert_eq!(Task::preemption_count(&system, 0, 0, TimeUnit::from(20)), 1);
+}
+
+#[test]
+fn worst_case_response_time_from_curve() {
+    let example_9 = crate::util::example_9();
+    let view = example_9.view();
+    let system = view.as_system();
+
+    for (server_index, server) in system.as_servers().iter().enumerate() {
+        let up_to = system.analysis_end(server_index);
+
+        for (task_index, task) in server.as_tasks().iter().enumerate() {
+            // the curve may extend past the execution needed for the analysis
+            let execution: Curve<_> =
+                Task::fixed_actual_execution_curve_iter(&system, server_index, task_index)
+                    .take_up_to_capacity(task.released_demand_before(up_to * 2))
+                    .collect_curve();
+
+            assert_eq!(
+                Task::worst_case_response_time_from_curve(&execution, task, up_to),
+                Task::fixed_worst_case_response_time(&system, server_index, task_index, up_to)
+            );
+        }
+    }
+}
+
+#[test]
+fn demand_in_window() {
+    // jobs arrive at 3, 8, 13, 18, ...
+    let task = Task::new(2, 5, 3);
+
+    assert_eq!(
+        task.demand_in_window(TimeUnit::ZERO, TimeUnit::from(3)),
+        TimeUnit::ZERO
+    );
+    assert_eq!(
+        task.demand_in_window(TimeUnit::from(3), TimeUnit::from(8)),
+        TimeUnit::from(2)
+    );
+    assert_eq!(
+        task.demand_in_window(TimeUnit::from(4), TimeUnit::from(19)),
+        TimeUnit::from(6)
+    );
+    // empty window
+    assert_eq!(
+        task.demand_in_window(TimeUnit::from(8), TimeUnit::from(8)),
+        TimeUnit::ZERO
+    );
+
+    // the job arriving at -2 counts as arriving at the origin, together with the jobs at 3 and 8
+    let phased = Task::with_phase(3, 5, -2);
+    assert_eq!(
+        phased.demand_in_window(TimeUnit::ZERO, TimeUnit::from(10)),
+        TimeUnit::from(9)
+    );
+    assert_eq!(
+        phased.demand_in_window(TimeUnit::ONE, TimeUnit::from(10)),
+        TimeUnit::from(6)
+    );
+}
+
+#[test]
+fn jitter() {
+    // nominal arrivals 0, 4, 8, 12, the first job is released 3 late at 3
+    let task = Task::new(2, 4, 0).with_jitter(TimeUnit::from(3));
+
+    assert_eq!(task.job_arrival(0), TimeUnit::ZERO);
+    assert_eq!(task.job_arrival(1), TimeUnit::from(4));
+    assert_eq!(task.job_arrival(2), TimeUnit::from(8));
+
+    // the second job is released at its nominal arrival 4,
+    // compressed against the late first job and queued behind its demand
+    let demand: Vec<_> = task.into_iter().take(4).collect();
+    assert_eq!(
+        demand,
+        vec![
+            Window::new(3, 5),
+            Window::new(5, 7),
+            Window::new(8, 10),
+            Window::new(12, 14)
+        ]
+    );
+
+    assert_eq!(
+        task.released_demand_before(TimeUnit::from(3)),
+        TimeUnit::ZERO
+    );
+    assert_eq!(
+        task.released_demand_before(TimeUnit::from(4)),
+        TimeUnit::from(2)
+    );
+    assert_eq!(
+        task.released_demand_before(TimeUnit::from(5)),
+        TimeUnit::from(4)
+    );
+
+    let tasks = &[task];
+    let servers = &[Server::deferrable(tasks, 4.into(), 4.into())];
+    let system = System::new(servers);
+
+    // the first job arrives at 0 and finishes at 5, the compressed second job arrives at 4 and finishes at 7
+    assert_eq!(
+        Task::fixed_worst_case_response_time(&system, 0, 0, TimeUnit::from(20)),
+        TimeUnit::from(5)
+    );
+
+    // a lower priority task sees the compressed demand of the jittered task
+    let tasks = &[task, Task::new(1, 8, 4)];
+    let servers = &[Server::deferrable(tasks, 4.into(), 4.into())];
+    let system = System::new(servers);
+
+    // the job arriving at 4 has to wait for the compressed pair and finishes at 8
+    assert_eq!(
+        Task::fixed_worst_case_response_time(&system, 0, 1, TimeUnit::from(8)),
+        TimeUnit::from(4)
+    );
+
+    // without jitter every job finishes 2 after its arrival
+    let tasks = &[Task::new(2, 4, 0)];
+    let servers = &[Server::deferrable(tasks, 4.into(), 4.into())];
+    let system = System::new(servers);
+
+    assert_eq!(
+        Task::fixed_worst_case_response_time(&system, 0, 0, TimeUnit::from(20)),
+        TimeUnit::from(2)
+    );
+}
+
+#[test]
+fn with_parameters() {
+    let task = Task::new(2, 8, 1).with_jitter(TimeUnit::from(3));
+
+    let updated = task
+        .with_demand(TimeUnit::from(4))
+        .with_interval(TimeUnit::from(10))
+        .with_offset(TimeUnit::from(5));
+
+    assert_eq!(updated.demand, TimeUnit::from(4));
+    assert_eq!(updated.interval, TimeUnit::from(10));
+    assert_eq!(updated.offset, TimeUnit::from(5));
+    assert_eq!(updated.jitter, TimeUnit::from(3));
+
+    // the original is unchanged
+    assert_eq!(task.demand, TimeUnit::from(2));
+    assert_eq!(task.interval, TimeUnit::from(8));
+    assert_eq!(task.offset, TimeUnit::from(1));
+}
+
+#[test]
+#[should_panic(expected = "shorter than its demand")]
+fn with_demand_exceeding_interval() {
+    let _ = Task::new(2, 8, 0).with_demand(TimeUnit::from(9));
+}
+
+#[test]
+#[should_panic(expected = "shorter than its demand")]
+fn with_interval_below_demand() {
+    let _ = Task::new(2, 8, 0).with_interval(TimeUnit::from(1));
+}
+
+#[test]
+fn hyperperiod() {
+    let coprime_a = Task::new(1, 4, 0);
+    let coprime_b = Task::new(2, 9, 3);
+
+    assert_eq!(coprime_a.hyperperiod_with(&coprime_b), TimeUnit::from(36));
+    assert_eq!(coprime_b.hyperperiod_with(&coprime_a), TimeUnit::from(36));
+
+    let shared_a = Task::new(1, 6, 0);
+    let shared_b = Task::new(2, 10, 0);
+
+    assert_eq!(shared_a.hyperperiod_with(&shared_b), TimeUnit::from(30));
+    assert_eq!(shared_a.hyperperiod_with(&shared_a), TimeUnit::from(6));
+
+    assert_eq!(
+        Task::set_hyperperiod(&[coprime_a, coprime_b, shared_a, shared_b]),
+        TimeUnit::from(180)
+    );
+    assert_eq!(Task::set_hyperperiod(&[]), TimeUnit::ONE);
+
+    // dividing before multiplying avoids overflowing on a representable hyperperiod
+    let large = usize::MAX / 3 * 2;
+    let large_a = Task::new(1, large, 0);
+    let large_b = Task::new(1, large / 2, 0);
+
+    assert_eq!(large_a.hyperperiod_with(&large_b), TimeUnit::from(large));
+}
@@ -0,0 +1,84 @@
+use crate::rta_lib::time::{TimeUnit, TimeUnitParseError};
+use crate::rta_lib::window::WindowEnd;
+use core::time::Duration;
+
+#[test]
+fn duration_round_trip() {
+    let tick = Duration::from_micros(250);
+
+    let time = TimeUnit::from_duration(Duration::from_millis(3), tick);
+    assert_eq!(time, TimeUnit::from(12));
+    assert_eq!(time.to_duration(tick), Duration::from_millis(3));
+
+    // not a multiple of the tick, rounded down
+    let time = TimeUnit::from_duration(Duration::from_micros(3_100), tick);
+    assert_eq!(time, TimeUnit::from(12));
+
+    // crossing the second boundary
+    let tick = Duration::from_millis(300);
+    assert_eq!(
+        TimeUnit::from(7).to_duration(tick),
+        Duration::from_millis(2100)
+    );
+}
+
+#[test]
+fn checked_add() {
+    let one = TimeUnit::ONE;
+
+    assert_eq!((TimeUnit::MAX - one).checked_add(one), Some(TimeUnit::MAX));
+    assert_eq!(TimeUnit::MAX.checked_add(one), None);
+
+    assert_eq!(
+        WindowEnd::Finite(TimeUnit::MAX).checked_add(WindowEnd::Finite(TimeUnit::ZERO)),
+        Some(WindowEnd::Finite(TimeUnit::MAX))
+    );
+    assert_eq!(
+        WindowEnd::Finite(TimeUnit::MAX).checked_add(WindowEnd::Finite(one)),
+        None
+    );
+    assert_eq!(
+        WindowEnd::Finite(TimeUnit::MAX).checked_add(WindowEnd::Infinite),
+        Some(WindowEnd::Infinite)
+    );
+}
+
+#[test]
+fn checked_mul() {
+    let max = TimeUnit::MAX.as_unit();
+
+    assert_eq!(
+        TimeUnit::from(max / 2).checked_mul(2),
+        Some(TimeUnit::from(max - 1))
+    );
+    assert_eq!(TimeUnit::from(max / 2 + 1).checked_mul(2), None);
+    assert_eq!(TimeUnit::MAX.checked_mul(0), Some(TimeUnit::ZERO));
+}
+
+#[test]
+fn saturating_sub() {
+    let three = TimeUnit::from(3);
+    let five = TimeUnit::from(5);
+
+    assert_eq!(five.saturating_sub(three), TimeUnit::from(2));
+    assert_eq!(three.saturating_sub(five), TimeUnit::ZERO);
+}
+
+#[test]
+fn parse_time_unit() {
+    assert_eq!("42".parse::<TimeUnit>(), Ok(TimeUnit::from(42)));
+    assert_eq!("0".parse::<TimeUnit>(), Ok(TimeUnit::ZERO));
+
+    assert_eq!("-3".parse::<TimeUnit>(), Err(TimeUnitParseError::Negative));
+
+    for invalid in ["", "abc", "4.5", "1/2", " 7"] {
+        assert!(
+            matches!(
+                invalid.parse::<TimeUnit>(),
+                Err(TimeUnitParseError::Invalid(_))
+            ),
+            "{:?} should be invalid",
+            invalid
+        );
+    }
+}
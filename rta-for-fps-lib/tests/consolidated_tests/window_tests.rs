@@ -1,4 +1,5 @@
-use crate::rta_lib::window::{Demand, Supply, Window};
+use crate::rta_lib::time::TimeUnit;
+use crate::rta_lib::window::{Demand, Supply, Window, WindowEnd};
 
 #[test]
 fn aggregate_windows() {
@@ -106,3 +107,121 @@ fn window_delta_d() {
 
     assert_eq!(result_supply, expected_remaining_supply);
 }
+
+#[test]
+fn window_delta_reconstruct_supply() {
+    let demand = Window::<Demand>::new(3, 7);
+
+    let cases = [
+        Window::<Supply>::new(0, 5),
+        Window::new(0, 2),
+        Window::new(8, 12),
+        Window::new(0, WindowEnd::Infinite),
+    ];
+
+    for supply in cases.iter() {
+        let result = Window::delta(supply, &demand);
+        assert_eq!(result.reconstruct_supply(), supply.length());
+    }
+}
+
+#[test]
+fn split_window() {
+    let window = Window::<Demand>::new(2, 6);
+
+    assert_eq!(
+        window.split_at(TimeUnit::from(4)),
+        (Window::new(2, 4), Window::new(4, 6))
+    );
+
+    let (head, tail) = window.split_at(TimeUnit::from(2));
+    assert!(head.is_empty());
+    assert_eq!(tail, window);
+
+    let (head, tail) = window.split_at(TimeUnit::from(8));
+    assert_eq!(head, window);
+    assert!(tail.is_empty());
+
+    let infinite = Window::<Demand>::new(2, WindowEnd::Infinite);
+    assert_eq!(
+        infinite.split_at(TimeUnit::from(10)),
+        (Window::new(2, 10), Window::new(10, WindowEnd::Infinite))
+    );
+}
+
+#[test]
+fn window_end_max() {
+    let two = WindowEnd::Finite(TimeUnit::from(2));
+    let five = WindowEnd::Finite(TimeUnit::from(5));
+
+    assert_eq!(two.max(five), five);
+    assert_eq!(five.max(two), five);
+    assert_eq!(two.max(WindowEnd::Infinite), WindowEnd::Infinite);
+    assert_eq!(WindowEnd::Infinite.max(two), WindowEnd::Infinite);
+    assert_eq!(
+        WindowEnd::Infinite.max(WindowEnd::Infinite),
+        WindowEnd::Infinite
+    );
+}
+
+#[test]
+fn scale_window() {
+    let finite = Window::<Supply>::new(2, 5);
+    assert_eq!(finite.scale(3), Window::new(6, 15));
+    assert_eq!(
+        finite.scale(3).length(),
+        WindowEnd::Finite(TimeUnit::from(9))
+    );
+
+    let infinite = Window::<Supply>::new(2, WindowEnd::Infinite);
+    assert_eq!(infinite.scale(3), Window::new(6, WindowEnd::Infinite));
+}
+
+#[test]
+fn finite_window_length() {
+    let finite = Window::<Supply>::new(3, 7);
+    let infinite = Window::<Supply>::new(3, WindowEnd::Infinite);
+
+    assert_eq!(finite.length_finite(), Some(TimeUnit::from(4)));
+    assert_eq!(finite.length_or_panic(), TimeUnit::from(4));
+    assert_eq!(infinite.length_finite(), None);
+}
+
+#[test]
+#[should_panic]
+fn infinite_window_length_or_panic() {
+    let _ = Window::<Supply>::new(3, WindowEnd::Infinite).length_or_panic();
+}
+
+#[test]
+fn overlaps_strictly() {
+    let w1 = Window::<Demand>::new(2, 4);
+    let touching = Window::<Demand>::new(4, 6);
+    let overlapping = Window::<Demand>::new(3, 6);
+    let infinite = Window::<Demand>::new(5, WindowEnd::Infinite);
+
+    assert!(w1.overlaps(&touching));
+    assert!(!w1.overlaps_strictly(&touching));
+    assert!(!touching.overlaps_strictly(&w1));
+
+    assert!(w1.overlaps_strictly(&overlapping));
+    assert!(overlapping.overlaps_strictly(&w1));
+
+    assert!(!w1.overlaps_strictly(&infinite));
+    assert!(touching.overlaps_strictly(&infinite));
+}
+
+#[test]
+fn display() {
+    assert_eq!(WindowEnd::Finite(TimeUnit::from(7)).to_string(), "7");
+    assert_eq!(WindowEnd::Infinite.to_string(), "∞");
+
+    assert_eq!(Window::<Demand>::new(2, 4).to_string(), "[2, 4)");
+    assert_eq!(
+        Window::<Supply>::new(5, WindowEnd::Infinite).to_string(),
+        "[5, ∞)"
+    );
+
+    // Debug stays unchanged
+    assert_eq!(format!("{:?}", WindowEnd::Infinite), "Infinite");
+}